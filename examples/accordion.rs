@@ -128,10 +128,12 @@ pub fn accordion_page(
                     event: EventFlags::MouseWheel,
                     extra: Scrolling::Y,
                     extra: SharedPosition::new(false, false),
-                    signal: sender::<SharedPosition>(pos_text),
                     extra: GreaterBoundingBox::new(),
-                    signal: sender::<GreaterBoundingBoxPx>(cov_send),
-                    signal: sender::<GreaterBoundingBoxPercent>(cov_percent_send),
+                    signals: (
+                        sender::<SharedPosition>(pos_text),
+                        sender::<GreaterBoundingBoxPx>(cov_send),
+                        sender::<GreaterBoundingBoxPercent>(cov_percent_send),
+                    ),
                     // coverage_px: cov_send,
                     // coverage_percent: cov_percent_send,
                     anchor: Top,
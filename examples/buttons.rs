@@ -192,6 +192,7 @@ pub fn init(mut commands: RCommands) {
         font_size: em(2),
         cursor: CursorIcon::Pointer,
         on_click: send,
+        payload: "!!!!",
         child: rectangle!{
             dimension: size2!(100%, 100%),
             color: color!(blue500),
@@ -221,9 +222,11 @@ pub fn init(mut commands: RCommands) {
             z: 0.1
         },
         system: |sender: Sender<ButtonClick>, world: AsyncWorldMut| {
-            sender.recv().await;
+            let Some(payload) = sender.recv_as::<&'static str>().await else {
+                return AsyncOk;
+            };
             println!("Clicked");
-            world.send::<Fac<String>>("Clicked", "!!!!".to_owned()).await;
+            world.send::<Fac<String>>("Clicked", payload.to_owned()).await;
         }
     });
 }
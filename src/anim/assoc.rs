@@ -3,7 +3,7 @@ use bevy::ecs::{component::Component, system::Query};
 use bevy::sprite::TextureAtlas;
 use bevy::ecs::query::{QueryData, QueryFilter};
 use crate::Coloring;
-use crate::{Transform2D, Dimension, Opacity};
+use crate::{Transform2D, Dimension, FontSize, Opacity, Skew};
 use super::{Interpolation, Interpolate, Offset, Rotation, Scale, Index};
 
 
@@ -40,6 +40,20 @@ impl InterpolateAssociation for (Transform2D, Offset) {
     }
 }
 
+impl InterpolateAssociation for (Skew, Skew) {
+    type Component = Skew;
+    type Interpolation = Skew;
+    type Condition = ();
+
+    fn set<'t>(component: &mut Self::Component, value: <Self::Interpolation as Interpolation>::FrontEnd) {
+        component.0 = value;
+    }
+
+    fn get(component: &Self::Component) -> <Self::Interpolation as Interpolation>::FrontEnd {
+        component.0
+    }
+}
+
 impl InterpolateAssociation for (Transform2D, Rotation) {
     type Component = Transform2D;
     type Interpolation = Rotation;
@@ -68,6 +82,27 @@ impl InterpolateAssociation for (Transform2D, Scale) {
     }
 }
 
+impl InterpolateAssociation for (Dimension, FontSize) {
+    type Component = Dimension;
+    type Interpolation = FontSize;
+    type Condition = ();
+
+    fn set<'t>(component: &mut Self::Component, value: <Self::Interpolation as Interpolation>::FrontEnd) {
+        match component.font_size {
+            FontSize::None => component.font_size = FontSize::Ems(value),
+            _ => *component.font_size.raw_mut() = value,
+        }
+    }
+
+    fn get(component: &Self::Component) -> <Self::Interpolation as Interpolation>::FrontEnd {
+        match component.font_size {
+            // Inherits `em` from parent, same as `1 em`.
+            FontSize::None => 1.0,
+            FontSize::Pixels(v) | FontSize::Ems(v) | FontSize::Rems(v) => v,
+        }
+    }
+}
+
 impl InterpolateAssociation for (Dimension, Dimension) {
     type Component = Dimension;
     type Interpolation = Dimension;
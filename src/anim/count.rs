@@ -0,0 +1,121 @@
+use bevy::ecs::{component::Component, system::{Query, Res}};
+use bevy::{reflect::Reflect, text::Text, time::Time};
+
+use crate::dsl::WidgetWrite;
+use crate::widgets::TextFragment;
+use crate::widgets::inputbox::InputBox;
+use super::{AnimationPaused, Easing};
+
+/// Formatting of the number displayed by [`CountTo`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum CountFormat {
+    /// Round to the nearest integer.
+    #[default]
+    Integer,
+    /// Display a fixed number of decimal places.
+    Decimal(usize),
+}
+
+impl CountFormat {
+    pub fn format(&self, value: f32) -> String {
+        match self {
+            CountFormat::Integer => format!("{}", value.round() as i64),
+            CountFormat::Decimal(precision) => format!("{:.*}", precision, value),
+        }
+    }
+}
+
+/// Counts a number from `from` to `to` over `duration` seconds,
+/// writing it to a `Text`, `TextFragment` or `InputBox` via [`WidgetWrite`].
+///
+/// The exact value of `to` is written when finished.
+/// Text is only rewritten when the formatted value changes.
+/// Pauses while [`AnimationPaused`].
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct CountTo {
+    pub from: f32,
+    pub to: f32,
+    /// Duration in seconds.
+    pub duration: f32,
+    pub fmt: CountFormat,
+    pub easing: Easing,
+    elapsed: f32,
+    displayed: Option<String>,
+}
+
+impl CountTo {
+    pub fn new(from: f32, to: f32, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            fmt: CountFormat::Integer,
+            easing: Easing::Linear,
+            elapsed: 0.0,
+            displayed: None,
+        }
+    }
+
+    pub fn with_format(mut self, fmt: CountFormat) -> Self {
+        self.fmt = fmt;
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Obtain the current value.
+    pub fn get(&self) -> f32 {
+        if self.is_finished() {
+            return self.to;
+        }
+        let t = self.easing.get(self.elapsed / self.duration);
+        self.from + (self.to - self.from) * t
+    }
+
+    /// Returns true if the counter has reached `to`.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Count from the current value to a new target, restarting the timer.
+    pub fn count_to(&mut self, to: f32) {
+        self.from = self.get();
+        self.to = to;
+        self.elapsed = 0.0;
+        self.displayed = None;
+    }
+}
+
+pub(crate) fn count_to_system(
+    time: Res<Time>,
+    paused: Option<Res<AnimationPaused>>,
+    mut query: Query<(&mut CountTo, Option<&mut Text>, Option<&mut TextFragment>,
+        Option<&mut InputBox>, Option<&AnimationPaused>)>,
+) {
+    let delta = time.delta_seconds();
+    let paused = paused.as_deref();
+    for (mut count, text, frag, input, local) in query.iter_mut() {
+        if AnimationPaused::resolve(paused, local) {
+            continue;
+        }
+        if count.is_finished() && count.displayed.is_some() {
+            continue;
+        }
+        count.elapsed += delta;
+        let string = count.fmt.format(count.get());
+        if count.displayed.as_ref() == Some(&string) {
+            continue;
+        }
+        count.displayed = Some(string.clone());
+        if let Some(text) = text {
+            text.write(string)
+        } else if let Some(frag) = frag {
+            frag.write(string)
+        } else if let Some(input) = input {
+            input.write(string)
+        }
+    }
+}
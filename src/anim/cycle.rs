@@ -0,0 +1,56 @@
+use bevy::{ecs::{component::Component, system::{Query, Res}}, reflect::Reflect};
+use bevy::{render::color::Color, time::Time};
+
+use crate::{Coloring, Opacity};
+use super::{AnimationPaused, Attr};
+
+/// Cycles the hue of `Coloring` through the color wheel over time.
+///
+/// If `Interpolate<Color>` is present, it is moved along with the color.
+/// Pauses while the entity is not visible or [`AnimationPaused`].
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ColorCycle {
+    /// Degrees of hue rotated per second.
+    pub speed: f32,
+    /// Saturation of the generated color, in `0.0..=1.0`.
+    pub saturation: f32,
+    /// Lightness of the generated color, in `0.0..=1.0`.
+    pub lightness: f32,
+    /// Current hue in degrees.
+    pub hue: f32,
+}
+
+impl ColorCycle {
+    pub const fn new(speed: f32, saturation: f32, lightness: f32) -> Self {
+        Self { speed, saturation, lightness, hue: 0.0 }
+    }
+
+    /// Start the cycle at a specific hue in degrees.
+    pub const fn with_hue(mut self, hue: f32) -> Self {
+        self.hue = hue;
+        self
+    }
+}
+
+impl Default for ColorCycle {
+    fn default() -> Self {
+        Self::new(90.0, 1.0, 0.5)
+    }
+}
+
+pub(crate) fn color_cycle_system(
+    time: Res<Time>,
+    paused: Option<Res<AnimationPaused>>,
+    mut query: Query<(&mut ColorCycle, &Opacity, Attr<Coloring, Color>, Option<&AnimationPaused>)>,
+) {
+    let delta = time.delta_seconds();
+    let paused = paused.as_deref();
+    for (mut cycle, opacity, mut color, local) in query.iter_mut() {
+        if opacity.get() <= 0.0 || AnimationPaused::resolve(paused, local) {
+            continue;
+        }
+        cycle.hue = (cycle.hue + cycle.speed * delta).rem_euclid(360.0);
+        let alpha = color.get().a();
+        color.force_set(Color::hsla(cycle.hue, cycle.saturation, cycle.lightness, alpha));
+    }
+}
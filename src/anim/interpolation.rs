@@ -5,11 +5,11 @@ use bevy::{render::color::Color, time::Time};
 use bevy::ecs::{component::Component, system::{Query, Res}};
 use bevy::math::{Vec2, Vec4};
 use bevy_defer::{AsyncComponent, AsyncComponentDeref, AsyncResult};
-use crate::{Opacity, Dimension};
+use crate::{Opacity, Dimension, FontSize, Skew};
 use interpolation::EaseFunction;
 use smallvec::SmallVec;
 
-use super::{Easing, Playback};
+use super::{AnimationPaused, Easing, Playback};
 
 #[derive(Debug, Clone, Component)]
 #[component(storage="SparseSet")]
@@ -26,6 +26,8 @@ pub struct Interpolate<T: Interpolation>{
     current: f32,
     time: f32,
     default_time: f32,
+    /// Delay in seconds before each interpolation starts.
+    delay: f32,
     playback: Playback,
 }
 
@@ -67,6 +69,7 @@ impl<T: Interpolation> Interpolate<T> {
             default_time: time,
             range: SmallVec::from_const([(position, 0.0)]),
             current: 0.0,
+            delay: 0.0,
             playback: Playback::Once,
         }
     }
@@ -79,6 +82,7 @@ impl<T: Interpolation> Interpolate<T> {
             default_time: time,
             range: SmallVec::from_const([(T::into_data(position), 0.0)]),
             current: 0.0,
+            delay: 0.0,
             playback: Playback::Once,
         }
     }
@@ -90,6 +94,7 @@ impl<T: Interpolation> Interpolate<T> {
             default_time: time,
             range: SmallVec::from_const([(T::into_data(position), 0.0)]),
             current: 0.0,
+            delay: 0.0,
             playback: Playback::Once,
         }
     }
@@ -101,6 +106,7 @@ impl<T: Interpolation> Interpolate<T> {
             default_time: time,
             range: positions.into_interpolate(),
             current: 0.0,
+            delay: 0.0,
             playback: Playback::Once,
         }
     }
@@ -112,6 +118,7 @@ impl<T: Interpolation> Interpolate<T> {
             default_time: time,
             range: positions.into_interpolate(),
             current: 0.0,
+            delay: 0.0,
             playback: Playback::Loop,
         }
     }
@@ -123,11 +130,27 @@ impl<T: Interpolation> Interpolate<T> {
             default_time: time,
             range: positions.into_interpolate(),
             current: 0.0,
+            delay: 0.0,
             playback: Playback::Repeat,
         }
     }
 
+    /// Delay each interpolation by some seconds, this does not count towards the duration.
+    ///
+    /// If the animation has not started, it is delayed as well.
+    /// For looping animations, only the first cycle is delayed.
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        if self.current == 0.0 {
+            self.current = -delay;
+        }
+        self
+    }
+
     fn get_data(&self) -> T::Data {
+        if self.range.len() > 1 && self.current < 0.0 {
+            return self.range.first().unwrap().0;
+        }
         if self.range.len() == 1 || self.time <= 0.0 || (self.playback.is_once() && self.current >= self.time) {
             return self.range.last().expect("Interpolate has no value, this is a bug.").0;
         }
@@ -197,7 +220,7 @@ impl<T: Interpolation> Interpolate<T> {
             self.reverse()
         } else if self.target() != to {
             self.range = [(self.get_data(), 0.0), (T::into_data(to), 1.0)].into_iter().collect();
-            self.current = 0.0;
+            self.current = -self.delay;
             self.time = self.default_time;
         }
     }
@@ -230,7 +253,7 @@ impl<T: Interpolation> Interpolate<T> {
                 range.insert(0, (pos, 0.0))
             }
             self.range = range;
-            self.current = 0.0;
+            self.current = -self.delay;
             self.time = self.default_time;
         }
     }
@@ -245,7 +268,7 @@ impl<T: Interpolation> Interpolate<T> {
             range.insert(0, (pos, 0.0))
         }
         self.range = range;
-        self.current = 0.0;
+        self.current = -self.delay;
         self.time = time;
     }
 }
@@ -297,10 +320,16 @@ pub trait Interpolation: Sized + 'static {
     fn into_front_end(data: Self::Data) -> Self::FrontEnd;
     fn update_interpolate(
         time: Res<Time>,
-        mut query: Query<&mut Interpolate<Self>>
+        paused: Option<Res<AnimationPaused>>,
+        mut query: Query<(&mut Interpolate<Self>, Option<&AnimationPaused>)>
     ) {
         let delta = time.delta_seconds();
-        query.iter_mut().for_each(move |mut x| x.update(delta))
+        let paused = paused.as_deref();
+        query.iter_mut().for_each(move |(mut x, local)| {
+            if !AnimationPaused::resolve(paused, local) {
+                x.update(delta)
+            }
+        })
     }
 }
 
@@ -344,6 +373,13 @@ impl Interpolation for Scale {
     fn into_front_end(data: Self::Data) -> Self::FrontEnd { data }
 }
 
+impl Interpolation for Skew {
+    type FrontEnd = Vec2;
+    type Data = Vec2;
+    fn into_data(data: Self::FrontEnd) -> Self::Data { data }
+    fn into_front_end(data: Self::Data) -> Self::FrontEnd { data }
+}
+
 impl Interpolation for Index {
     type FrontEnd = usize;
     type Data = f32;
@@ -358,6 +394,18 @@ impl Interpolation for Dimension {
     fn into_front_end(data: Self::Data) -> Self::FrontEnd { data }
 }
 
+/// Interpolates the raw value of [`FontSize`], keeping its unit.
+///
+/// `FontSize::None` resolves to the parent's `em` and is treated as `1 em`. The interpolated value
+/// is resolved into `em` during layout, so children and `Text` follow it,
+/// unless the text is marked with `OptOutFontSizeSync`, which takes precedence.
+impl Interpolation for FontSize {
+    type FrontEnd = f32;
+    type Data = f32;
+    fn into_data(data: Self::FrontEnd) -> Self::Data { data }
+    fn into_front_end(data: Self::Data) -> Self::FrontEnd { data }
+}
+
 impl Interpolation for Color {
     type FrontEnd = Color;
     type Data = Vec4;
@@ -0,0 +1,115 @@
+use std::marker::PhantomData;
+
+use bevy::app::{App, FixedUpdate, Plugin};
+use bevy::asset::{Assets, Handle};
+use bevy::ecs::schedule::IntoSystemConfigs;
+use bevy::ecs::system::{Query, ResMut};
+use bevy::sprite::Material2d;
+
+use super::{Interpolate, Interpolation, InterpolationSet, InterpolationUpdateSet};
+
+/// A [`Material2d`] with a uniform driven by [`Interpolate<I>`].
+///
+/// The interpolated value is written to the material asset, so entities
+/// sharing a material handle also share the animated value.
+///
+/// Implement with [`interpolatable_material!`](crate::interpolatable_material)
+/// and register with [`MaterialInterpolationPlugin`].
+pub trait InterpolatableMaterial<I: Interpolation>: Material2d {
+    fn get(&self) -> I::FrontEnd;
+    fn set(&mut self, value: I::FrontEnd);
+
+    fn system(
+        mut materials: ResMut<Assets<Self>>,
+        query: Query<(&Handle<Self>, &Interpolate<I>)>,
+    ) {
+        for (handle, interpolate) in query.iter() {
+            let value = interpolate.get();
+            // `get_mut` marks the asset as modified, avoid it if unchanged.
+            if materials.get(handle).is_some_and(|x| x.get() != value) {
+                if let Some(material) = materials.get_mut(handle) {
+                    material.set(value);
+                }
+            }
+        }
+    }
+}
+
+/// Registers an [`InterpolatableMaterial`] so `Interpolate<I>` writes to material `M`.
+///
+/// If `I` is not one of `bevy_rectray`'s interpolations,
+/// use [`with_custom_interpolation`](Self::with_custom_interpolation)
+/// to also update `Interpolate<I>` every frame. Do not do this more than once per `I`.
+#[derive(Debug)]
+pub struct MaterialInterpolationPlugin<M, I> {
+    custom_interpolation: bool,
+    p: PhantomData<fn() -> (M, I)>,
+}
+
+impl<M, I> MaterialInterpolationPlugin<M, I> {
+    pub const fn new() -> Self {
+        Self { custom_interpolation: false, p: PhantomData }
+    }
+
+    /// Also update `Interpolate<I>`.
+    pub const fn with_custom_interpolation(mut self) -> Self {
+        self.custom_interpolation = true;
+        self
+    }
+}
+
+impl<M, I> Default for MaterialInterpolationPlugin<M, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: InterpolatableMaterial<I>, I: Interpolation + Send + Sync> Plugin for MaterialInterpolationPlugin<M, I> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, M::system.in_set(InterpolationSet));
+        if self.custom_interpolation {
+            app.add_systems(FixedUpdate, I::update_interpolate.in_set(InterpolationUpdateSet));
+        }
+    }
+}
+
+/// Implement [`InterpolatableMaterial`](crate::anim::InterpolatableMaterial)
+/// for fields of a [`Material2d`](bevy::sprite::Material2d).
+///
+/// ```
+/// #[derive(Debug, Default, Clone, AsBindGroup, TypePath, Asset)]
+/// pub struct Circle {
+///     #[uniform(0)]
+///     fill: Color,
+///     #[uniform(1)]
+///     opacity: f32,
+/// }
+///
+/// impl Material2d for Circle {
+///     fn fragment_shader() -> ShaderRef {
+///         "circle.wgsl".into()
+///     }
+/// }
+///
+/// interpolatable_material!(Circle {
+///     Color => fill,
+///     Opacity => opacity,
+/// });
+///
+/// app.add_plugins(MaterialInterpolationPlugin::<Circle, Color>::new());
+/// app.add_plugins(MaterialInterpolationPlugin::<Circle, Opacity>::new());
+/// ```
+#[macro_export]
+macro_rules! interpolatable_material {
+    ($material: ty {$($interpolation: ty => $field: ident),* $(,)?}) => {
+        $(impl $crate::anim::InterpolatableMaterial<$interpolation> for $material {
+            fn get(&self) -> <$interpolation as $crate::anim::Interpolation>::FrontEnd {
+                self.$field
+            }
+
+            fn set(&mut self, value: <$interpolation as $crate::anim::Interpolation>::FrontEnd) {
+                self.$field = value;
+            }
+        })*
+    };
+}
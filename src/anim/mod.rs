@@ -22,6 +22,9 @@
 //! )
 //! ```
 //!
+//! Add `delay seconds` before the mode to delay the property, e.g.
+//! `Opacity 0.5 CubicOut delay 0.2 init (0.0, 1.0)`.
+//!
 //! ## Mode
 //!
 //! * default:
@@ -60,6 +63,8 @@
 
 use bevy::{app::{FixedUpdate, Plugin, Update}, ecs::query::QueryData, render::color::Color, sprite::TextureAtlas};
 use bevy::ecs::schedule::{SystemSet, IntoSystemConfigs, IntoSystemSetConfigs};
+use bevy::ecs::{component::Component, system::Resource};
+use bevy::reflect::Reflect;
 
 use ::interpolation::Ease;
 /// Enum for easing functions.
@@ -74,9 +79,16 @@ mod assoc;
 pub use assoc::{Attr, InterpolateAssociation};
 mod fgsm;
 pub use fgsm::{Fgsm, FgsmPairing, ComponentFgsm};
+mod cycle;
+pub use cycle::ColorCycle;
+mod material;
+pub use material::{InterpolatableMaterial, MaterialInterpolationPlugin};
+mod count;
+pub use count::{CountTo, CountFormat};
 
 
-use crate::{Coloring, Dimension, Opacity, Transform2D};
+use crate::{Coloring, Dimension, FontSize, Opacity, Transform2D};
+pub use crate::Skew;
 
 /// A easing function.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -113,6 +125,20 @@ impl Easing {
     }
 }
 
+/// Pauses all [`Interpolate`] animations while `true`, targets are retained.
+///
+/// As a resource this pauses globally, as a component this overrides the global value.
+/// Time does not accumulate while paused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Resource, Component, Reflect)]
+pub struct AnimationPaused(pub bool);
+
+impl AnimationPaused {
+    /// Resolve the paused state of an entity.
+    pub fn resolve(global: Option<&Self>, local: Option<&Self>) -> bool {
+        local.or(global).map(|x| x.0).unwrap_or(false)
+    }
+}
+
 /// SystemSet for interpolation.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, SystemSet)]
 pub struct InterpolationSet;
@@ -137,7 +163,7 @@ impl VisibilityToggleItem<'_> {
                 inter.interpolate_to(if value {1.0} else {0.0});
             },
             None => {
-                self.opacity.disabled = value;
+                self.opacity.disabled = !value;
                 self.opacity.opacity = if value {1.0} else {0.0};
             }
         }
@@ -149,14 +175,20 @@ pub(crate) struct AnimationPlugin;
 impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app
+            .init_resource::<AnimationPaused>()
             .configure_sets(Update, InterpolationSet)
             .configure_sets(Update, InterpolationUpdateSet.after(InterpolationSet))
+            .configure_sets(FixedUpdate, InterpolationUpdateSet.after(InterpolationSet))
+            .add_systems(FixedUpdate, cycle::color_cycle_system.before(InterpolationSet))
+            .add_systems(FixedUpdate, count::count_to_system.before(InterpolationSet))
             .add_systems(FixedUpdate, (
                 <(Transform2D, Offset)>::system,
                 <(Transform2D, Rotation)>::system,
                 <(Transform2D, Offset)>::system,
                 <(Transform2D, Scale)>::system,
+                <(Skew, Skew)>::system,
                 <(Dimension, Dimension)>::system,
+                <(Dimension, FontSize)>::system,
                 <(Coloring, Color)>::system,
                 <(Opacity, Opacity)>::system,
                 <(TextureAtlas, Index)>::system,
@@ -165,7 +197,9 @@ impl Plugin for AnimationPlugin {
                 Offset::update_interpolate,
                 Rotation::update_interpolate,
                 Scale::update_interpolate,
+                Skew::update_interpolate,
                 Dimension::update_interpolate,
+                FontSize::update_interpolate,
                 Color::update_interpolate,
                 Opacity::update_interpolate,
                 Index::update_interpolate,
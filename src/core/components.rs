@@ -1,6 +1,7 @@
 
 use bevy::{prelude::*, reflect::Reflect, math::Affine2};
 
+use bevy_defer::signals::SignalId;
 use crate::util::DslFrom;
 
 /// Tracked color of `aoui` does not propagate.
@@ -88,6 +89,39 @@ impl Opacity {
     }
 }
 
+/// Multiplies the computed opacity of this entity and all its descendants.
+///
+/// Useful for fading an entire panel without modifying opacity of each child.
+/// Nested groups multiply.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct OpacityGroup(pub f32);
+
+impl Default for OpacityGroup {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Multiplies the computed opacity of a sprite displaying a camera frame's render target.
+///
+/// Written by the camera frame with its relative opacity, see `CameraDisplay`.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct DisplayOpacity(pub(crate) f32);
+
+impl Default for DisplayOpacity {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Disables an entity while its [`DisplayIfFade`](crate::widgets::util::DisplayIfFade)
+/// is fading out or hidden.
+///
+/// Combined with [`Opacity::disabled`] into [`Opacity::computed_disabled`],
+/// so that `disabled` stays under user control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Default, Reflect)]
+pub struct FadeDisabled(pub(crate) bool);
+
 impl Default for Opacity {
     fn default() -> Self {
         Self::OPAQUE
@@ -117,6 +151,182 @@ impl DslFrom<bool> for Opacity {
 #[component(storage="SparseSet")]
 pub struct IgnoreAlpha;
 
+/// Fit the image of a `Sprite` in its owned dimension.
+///
+/// Has no effect if dimension is `Copied`.
+/// `Contain` and `Cover` always use the entire image and overwrite `Sprite::rect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Default, Reflect)]
+pub enum SpriteFit {
+    /// Stretch the image to the dimension.
+    #[default]
+    Fill,
+    /// Scale the image to fit inside the dimension, preserving aspect ratio.
+    Contain,
+    /// Scale the image to cover the dimension, preserving aspect ratio.
+    ///
+    /// The image is center cropped.
+    Cover,
+}
+
+/// Repeat the image of a sprite across its dimension.
+///
+/// The image is repeated `repeat` times on each axis, non-integer values cut off the last tile.
+/// Overwrites `Sprite::rect` and sets the image's sampler to repeat,
+/// which affects all sprites sharing the image, use a separate copy of the image if this is not desired.
+///
+/// Since `repeat` is a count, tiles scale with the dimension regardless of DPI.
+/// Should not be used with [`SpriteFit`].
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct TileMode {
+    pub repeat: Vec2,
+}
+
+impl TileMode {
+    pub const fn new(repeat: Vec2) -> Self {
+        Self { repeat }
+    }
+}
+
+impl Default for TileMode {
+    fn default() -> Self {
+        Self::new(Vec2::ONE)
+    }
+}
+
+/// Minimum size of a text's copied dimension, in pixels.
+///
+/// Prevents empty or changing text from collapsing and moving its siblings.
+/// Clamped to `Text2dBounds` so wrapping text does not exceed its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Default, Reflect)]
+pub struct TextMinSize(pub Vec2);
+
+/// Move this sprite's anchor towards another entity's position every frame.
+///
+/// The target's position is the center of its `RotatedRect`,
+/// or the translation of its `GlobalTransform` if not a sprite.
+/// Requires `offset` to be in pixels. If the target is despawned, this component is removed.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct FollowTarget {
+    pub entity: Entity,
+    /// Offset from the target's position in pixels.
+    pub offset: Vec2,
+    /// Fraction of the remaining distance moved per frame at 60 fps, `1.0` snaps to the target.
+    pub lerp: f32,
+}
+
+impl FollowTarget {
+    pub const fn new(entity: Entity) -> Self {
+        Self { entity, offset: Vec2::ZERO, lerp: 1.0 }
+    }
+
+    pub const fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub const fn with_lerp(mut self, lerp: f32) -> Self {
+        self.lerp = lerp;
+        self
+    }
+}
+
+/// Uniformly scale the children of this sprite so they fit in its dimension.
+///
+/// The bounds of all children are measured around this sprite's center
+/// and a single scale is applied to the frame they are placed in,
+/// so layouts are scaled as a whole. `Transform2D::scale` is not modified.
+/// Applied after the layout pass, so the result is visible on the next frame.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ScaleToFit {
+    /// If set, children smaller than this sprite are scaled up to fit.
+    pub upscale: bool,
+    pub(crate) factor: f32,
+}
+
+impl ScaleToFit {
+    /// Only scale down overflowing children.
+    pub const SHRINK: Self = Self { upscale: false, factor: 1.0 };
+    /// Scale children up or down to fit.
+    pub const FIT: Self = Self { upscale: true, factor: 1.0 };
+
+    /// Scale currently applied to children, multiplied with their own scale.
+    pub fn factor(&self) -> f32 {
+        self.factor
+    }
+}
+
+impl Default for ScaleToFit {
+    fn default() -> Self {
+        Self::SHRINK
+    }
+}
+
+/// Align this text on its baseline when in a horizontal `SpanLayout` or `ParagraphLayout`,
+/// instead of by its `anchor`.
+///
+/// Baseline aligned items in the same line share a baseline,
+/// other items in the line are still aligned by their `anchor`.
+/// The baseline is computed from font metrics in `PostUpdate`.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Default, Reflect)]
+pub struct BaselineAlign {
+    /// Distance from the top of the text to its first baseline, in pixels.
+    pub(crate) ascent: f32,
+}
+
+impl BaselineAlign {
+    /// Distance from the top of the text to its first baseline, in pixels.
+    pub fn ascent(&self) -> f32 {
+        self.ascent
+    }
+}
+
+/// Sends the new size of this sprite in pixels when `DimensionData::size`
+/// changes by more than `epsilon` on either axis.
+///
+/// As a `SignalId`, sends `Vec2`. Sent in `PostUpdate` after the layout pass.
+///
+/// Compared against the last sent size instead of last frame's,
+/// so a reaction that resizes this sprite back does not send again,
+/// and a reaction that converges stops sending.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct SizeChanged {
+    /// Minimum change in pixels to send a signal.
+    pub epsilon: f32,
+    last: Option<Vec2>,
+}
+
+impl SignalId for SizeChanged {
+    type Data = Vec2;
+}
+
+impl SizeChanged {
+    pub const fn new(epsilon: f32) -> Self {
+        Self { epsilon, last: None }
+    }
+
+    /// Returns the last sent size.
+    pub fn last(&self) -> Option<Vec2> {
+        self.last
+    }
+
+    /// Returns true if `size` should be sent, and records it as sent.
+    pub(crate) fn update(&mut self, size: Vec2) -> bool {
+        match self.last {
+            Some(last) if (size - last).abs().cmple(Vec2::splat(self.epsilon)).all() => false,
+            _ => {
+                self.last = Some(size);
+                true
+            }
+        }
+    }
+}
+
+impl Default for SizeChanged {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
 /// Data related to clipping.
 #[derive(Debug, Clone, Copy, Component, Default, Reflect)]
 pub struct Clipping {
@@ -1,4 +1,6 @@
-use bevy::{ecs::{component::Component, query::QueryData}, math::Vec2, reflect::Reflect};
+use bevy::{core::FrameCount, ecs::{component::Component, query::QueryData, world::Mut}, math::Vec2, reflect::Reflect};
+use bevy_defer::{AsyncComponent, AsyncComponentDeref, AsyncResult};
+use ref_cast::RefCast;
 
 use crate::{Size2, FontSize};
 
@@ -54,6 +56,31 @@ pub struct DimensionData {
     pub em: f32,
 }
 
+impl DimensionData {
+    /// Evaluated size in pixels.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Aspect ratio of the sprite.
+    pub fn aspect(&self) -> f32 {
+        self.aspect
+    }
+
+    /// Font size `em` on this sprite.
+    pub fn em(&self) -> f32 {
+        self.em
+    }
+}
+
+/// A component that copies `em` from [`DimensionData`] every frame.
+///
+/// Register [`sync_em`](crate::sync_em) for custom implementors.
+pub trait SyncEm: Component {
+    /// Set the font size, should not trigger change detection if unchanged.
+    fn sync_em(this: &mut Mut<Self>, em: f32);
+}
+
 /// A mutable query that obtains both [`Dimension`] and [`DimensionData`]
 #[derive(Debug, QueryData)]
 #[query_data(mutable)]
@@ -345,3 +372,45 @@ impl DimensionMutItem<'_> {
         self.source.is_copied()
     }
 }
+
+#[doc(hidden)]
+#[derive(Debug, RefCast)]
+#[repr(transparent)]
+pub struct AsyncDimensionData<'t>(AsyncComponent<'t, DimensionData>);
+
+impl AsyncComponentDeref for DimensionData {
+    type Target<'t> = AsyncDimensionData<'t>;
+
+    fn async_deref<'a, 'b>(this: &'b AsyncComponent<'a, Self>) -> &'b Self::Target<'a> {
+        AsyncDimensionData::ref_cast(this)
+    }
+}
+
+impl AsyncDimensionData<'_> {
+    /// Wait until `size` is non-zero and unchanged for a frame, i.e. text has been measured.
+    ///
+    /// Checked once per frame by [`FrameCount`], returns `None` if not settled after `max_frames` frames.
+    pub async fn settled(&self, max_frames: usize) -> AsyncResult<Option<Vec2>> {
+        let world = bevy_defer::world();
+        let frame_count = world.resource::<FrameCount>();
+        let start = frame_count.get(|x| x.0).await?;
+        let mut last_frame = None;
+        let mut last = Vec2::ZERO;
+        loop {
+            // The executor runs multiple times a frame, only sample on a new frame.
+            let frame = frame_count.get(|x| x.0).await?;
+            if last_frame == Some(frame) {
+                continue;
+            }
+            if frame.wrapping_sub(start) as usize >= max_frames {
+                return Ok(None);
+            }
+            let size = self.0.get(|x| x.size).await?;
+            if size != Vec2::ZERO && size == last {
+                return Ok(Some(size));
+            }
+            last = size;
+            last_frame = Some(frame);
+        }
+    }
+}
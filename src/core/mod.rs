@@ -13,7 +13,8 @@ pub use components::*;
 pub use hitbox::*;
 pub use scaling::*;
 
-pub use transform::{Transform2D, BuildTransform, BuildMeshTransform};
-pub use dimension::{Dimension, DimensionData, DimensionType, DimensionMut};
+pub use transform::{Transform2D, Skew, BuildTransform, BuildMeshTransform};
+pub use dimension::{Dimension, DimensionData, DimensionType, DimensionMut, SyncEm};
+pub use systems::sync_em;
 
 pub mod bundles;
@@ -12,9 +12,24 @@ type REntity<'t> = (
     &'t mut Opacity,
     &'t mut Clipping,
     &'t LayoutControl,
+    Option<&'t BaselineAlign>,
+    Option<&'t ScaleToFit>,
 );
 
-const Z_INCREMENT: f32 = 0.01;
+/// `z` used relative to the parent if [`Transform2D::z`] is `0`.
+pub(crate) const Z_INCREMENT: f32 = 0.01;
+
+/// Scale the rectangle children are placed in around its center by [`ScaleToFit`].
+fn content_rect(rect: RotatedRect, fit: Option<&ScaleToFit>) -> RotatedRect {
+    match fit.map(|x| x.factor()) {
+        Some(factor) if factor != 1.0 => RotatedRect {
+            affine: rect.affine * Affine2::from_scale(Vec2::splat(factor)),
+            scale: rect.scale * factor,
+            ..rect
+        },
+        _ => rect,
+    }
+}
 
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::needless_pass_by_ref_mut)]
@@ -22,8 +37,9 @@ fn propagate(
     parent: ParentInfo,
     entity: Entity,
     rem: f32,
+    direction: LayoutDirection,
     mut_query: &mut Query<REntity>,
-    layout_query: &mut Query<&mut Container>,
+    layout_query: &mut Query<(&mut Container, Option<&LayoutDirection>, Option<&PaddingSides>)>,
     parent_query: &Query<&Parent>,
     child_query: &Query<&Children>,
     not_root: &Query<Entity, Without<Detach>>,
@@ -36,7 +52,7 @@ fn propagate(
     }
 
     // SAFETY: safe since double mut access is gated by the hierarchy check
-    let Ok((entity, mut dim, transform, mut orig, mut opacity, mut clipping, ..))
+    let Ok((entity, mut dim, transform, mut orig, mut opacity, mut clipping, .., fit))
         = (unsafe {mut_query.get_unchecked(entity)}) else {return};
 
     let (dimension, em) = dim.update(parent.dimension, parent.em, rem);
@@ -46,7 +62,7 @@ fn propagate(
 
     opacity.occluded = false;
 
-    if let Ok(mut layout) = layout_query.get_mut(entity) {
+    if let Ok((mut layout, layout_dir, sides)) = layout_query.get_mut(entity) {
         let children = not_root.iter_many(child_query.get(entity).map(|x| x.iter()).into_iter().flatten());
         let mut other_entities = Vec::new();
         let mut args = Vec::new();
@@ -59,7 +75,7 @@ fn propagate(
             let dimension = if dim.is_owned() {dimension} else {Vec2::ZERO};
 
             // SAFETY: safe since double mut access is gated by the hierarchy check
-            if let Ok((_, mut child_dim, child_transform, .., control)) = unsafe { mut_query.get_unchecked(child) } {
+            if let Ok((_, mut child_dim, child_transform, .., control, baseline, _)) = unsafe { mut_query.get_unchecked(child) } {
                 match control {
                     LayoutControl::IgnoreLayout => other_entities.push((
                         child,
@@ -72,23 +88,41 @@ fn propagate(
                             anchor: child_transform.get_parent_anchor(),
                             dimension: child_dim.estimate(dimension, em, rem),
                             control: *control,
+                            baseline: baseline.map(|x| x.ascent),
                         });
                     }
                 };
             }
         }
         let margin = layout.margin.as_pixels(parent.dimension, em, rem);
+        let items: Vec<_> = args.iter().map(|x| (x.entity, x.anchor)).collect();
         let LayoutOutput{ mut entity_anchors, dimension: size, max_count } = layout.place(
-            &LayoutInfo { dimension, em, rem, margin },
+            &LayoutInfo {
+                dimension,
+                em,
+                rem,
+                margin,
+                direction: layout_dir.copied().unwrap_or(direction),
+            },
             args
         );
         layout.maximum = max_count;
-        let padding = layout.padding.as_pixels(parent.dimension, em, rem) * 2.0;
-        let fac = size / (size + padding);
-        let size = size + padding;
-        if !fac.is_nan() {
-            entity_anchors.iter_mut().for_each(|(_, anc)| *anc *= fac);
+        // children outside of `range` are skipped by the layout but still rendered.
+        if entity_anchors.len() < items.len() {
+            let placed: bevy::utils::HashSet<_> = entity_anchors.iter().map(|(e, _)| *e).collect();
+            other_entities.extend(items.into_iter().filter(|(e, _)| !placed.contains(e)));
         }
+        let padding = layout.padding.as_pixels(parent.dimension, em, rem);
+        let (min, max) = sides.copied().unwrap_or_default().as_pixels(dimension, em, rem);
+        let (min, max) = (padding + min, padding + max);
+        let total = size + min + max;
+        // `0 / 0` on an axis means no padding and no content on that axis.
+        let fac = size / total;
+        let fac = Vec2::select(fac.is_nan_mask(), Vec2::ONE, fac);
+        let shift = (min - max) / 2.0 / total;
+        let shift = Vec2::select(shift.is_nan_mask(), Vec2::ZERO, shift);
+        let size = total;
+        entity_anchors.iter_mut().for_each(|(_, anc)| *anc = *anc * fac + shift);
         dim.dynamic.size = size;
         let rect = RotatedRect::construct(
             &parent,
@@ -108,7 +142,7 @@ fn propagate(
 
         let info = ParentInfo {
             entity: Some(entity),
-            rect,
+            rect: content_rect(rect, fit),
             anchor: None,
             dimension: size,
             em,
@@ -145,7 +179,7 @@ fn propagate(
     if let Ok(children) = child_query.get(entity) {
         let info = ParentInfo {
             entity: Some(entity),
-            rect,
+            rect: content_rect(rect, fit),
             anchor: None,
             dimension,
             em,
@@ -204,13 +238,17 @@ pub fn compute_aoui_transforms<'t, R: RootQuery<'t>>(
     root: Query<R::Query, R::ReadOnly>,
     root_entities: Query<Entity, Or<(Without<Parent>, With<Detach>)>>,
     mut entity_query: Query<REntity>,
-    mut layout_query: Query<&mut Container>,
+    mut layout_query: Query<(&mut Container, Option<&LayoutDirection>, Option<&PaddingSides>)>,
     parent_query: Query<&Parent>,
     child_query: Query<&Children>,
     not_root: Query<Entity, Without<Detach>>,
+    in_safe_area: Query<(), With<InSafeArea>>,
     res_rem: Option<Res<RectrayRem>>,
+    safe_area: Option<Res<SafeArea>>,
+    res_direction: Option<Res<LayoutDirection>>,
 ) {
     let rem = res_rem.map(|x| x.get()).unwrap_or(16.0);
+    let direction = res_direction.map(|x| *x).unwrap_or_default();
 
     let (window_rect, dimension) = R::as_rect(&root);
 
@@ -224,8 +262,31 @@ pub fn compute_aoui_transforms<'t, R: RootQuery<'t>>(
         clip: None,
     };
 
+    let safe_info = match safe_area.as_deref() {
+        Some(safe_area) if !safe_area.is_empty() && dimension.cmpgt(Vec2::ZERO).all() => {
+            let (offset, safe_dimension) = safe_area.apply(dimension);
+            ParentInfo {
+                rect: RotatedRect {
+                    affine: window_rect.affine * Affine2::from_scale_angle_translation(
+                        safe_dimension / dimension,
+                        0.0,
+                        offset / dimension,
+                    ),
+                    ..window_rect
+                },
+                dimension: safe_dimension,
+                ..window_info
+            }
+        },
+        _ => window_info,
+    };
+
     for (entity, ..) in entity_query.iter_many(root_entities.iter()) {
-        queue.push((entity, window_info))
+        if in_safe_area.contains(entity) {
+            queue.push((entity, safe_info))
+        } else {
+            queue.push((entity, window_info))
+        }
     }
 
     while !queue.is_empty() {
@@ -233,6 +294,7 @@ pub fn compute_aoui_transforms<'t, R: RootQuery<'t>>(
             propagate(parent,
                 entity,
                 rem,
+                direction,
                 &mut entity_query,
                 &mut layout_query,
                 &parent_query,
@@ -252,13 +314,14 @@ struct OpacityStatus {
 
 fn propagate_aoui_opacity (
     queue: &mut Vec<(Entity, OpacityStatus)>,
-    query: &mut Query<(Entity, &mut Opacity)>,
+    query: &mut Query<(Entity, &mut Opacity, Option<&OpacityGroup>, Option<&DisplayOpacity>, Option<&FadeDisabled>)>,
     child_query: &Query<&Children>,
 ) {
     for (entity, status) in mem::take(queue) {
-        let Ok((_, mut opacity)) = query.get_mut(entity) else {continue};
-        opacity.computed_opacity = opacity.opacity * opacity.style_opacity * status.opacity;
-        opacity.computed_disabled = opacity.disabled || status.disabled;
+        let Ok((_, mut opacity, group, display, fade)) = query.get_mut(entity) else {continue};
+        let group = group.map(|x| x.0).unwrap_or(1.0) * display.map(|x| x.0).unwrap_or(1.0);
+        opacity.computed_opacity = opacity.opacity * opacity.style_opacity * status.opacity * group;
+        opacity.computed_disabled = opacity.disabled || fade.is_some_and(|x| x.0) || status.disabled;
         let status = OpacityStatus {
             opacity: opacity.computed_opacity,
             disabled: opacity.computed_disabled,
@@ -271,11 +334,11 @@ fn propagate_aoui_opacity (
 
 pub fn compute_aoui_opacity(
     root: Query<Entity, Without<Parent>>,
-    mut query: Query<(Entity, &mut Opacity)>,
+    mut query: Query<(Entity, &mut Opacity, Option<&OpacityGroup>, Option<&DisplayOpacity>, Option<&FadeDisabled>)>,
     child_query: Query<&Children>,
 ) {
     let mut queue: Vec<_> = query.iter_many(root.iter())
-        .map(|(e, _)| (e, OpacityStatus {
+        .map(|(e, ..)| (e, OpacityStatus {
             opacity: 1.0,
             disabled: false,
         }))
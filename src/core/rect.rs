@@ -89,15 +89,21 @@ impl Mul<Anchor> for Vec2 {
     }
 }
 
+/// `Inherit` is converted to `Center`.
 impl From<Anchor> for bevy::sprite::Anchor {
     fn from(val: Anchor) -> Self {
-        bevy::sprite::Anchor::Custom(val.0)
+        if val.is_inherit() {
+            bevy::sprite::Anchor::Center
+        } else {
+            bevy::sprite::Anchor::Custom(val.0)
+        }
     }
 }
 
+/// `Inherit` is converted to `Center`.
 impl From<&Anchor> for bevy::sprite::Anchor {
     fn from(val: &Anchor) -> Self {
-        bevy::sprite::Anchor::Custom(val.0)
+        (*val).into()
     }
 }
 
@@ -175,6 +181,26 @@ impl RotatedRect {
         Vec2::from_angle(-self.rotation).rotate(position - self.center())
     }
 
+    /// Express this rect in the local space of another rect, usually its parent.
+    ///
+    /// The result is centered on `parent`'s center, with `parent`'s
+    /// rotation and scale removed. Works with any number of rotated ancestors
+    /// since both rects are in screen space.
+    pub fn relative_to(&self, parent: &RotatedRect) -> RotatedRect {
+        let rotation = self.rotation - parent.rotation;
+        let scale = self.scale / parent.scale;
+        RotatedRect {
+            affine: Affine2::from_scale_angle_translation(
+                self.half_dim() * 2.0 / parent.scale,
+                rotation,
+                parent.local_space(self.center()) / parent.scale,
+            ),
+            rotation,
+            z: self.z - parent.z,
+            scale,
+        }
+    }
+
     /// Create an [`RotatedRect`] representing the sprite's position on the screen space
     /// and an `Affine3A` that converts into the `GlobalTransform` suitable from the screen space
     pub fn construct(parent: &ParentInfo, parent_anchor: Anchor, anchor: Anchor, offset: Vec2, dim: Vec2,
@@ -182,7 +208,8 @@ impl RotatedRect {
         let parent_anchor = parent.anchor.unwrap_or_else(||
             parent.rect.affine.transform_point2(parent_anchor.or(anchor).as_vec())
         );
-        // apply offset and dimension
+        // apply offset and dimension, `offset` is in the parent's local frame
+        // regardless of unit, percentages are resolved before this point.
         let self_center = offset + (center.as_vec() - anchor.as_vec()) * dim;
         let dir = (Anchor::CENTER.as_vec() - center.as_vec()) * dim;
 
@@ -229,3 +256,133 @@ const _: () = {
         }
     }
 };
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::FRAC_PI_2;
+    use bevy::math::{Affine2, Vec2};
+    use crate::Size2;
+    use super::{Anchor, ParentInfo, RotatedRect};
+
+    fn root(dimension: Vec2) -> ParentInfo {
+        ParentInfo {
+            entity: None,
+            rect: RotatedRect {
+                affine: Affine2::from_scale(dimension),
+                rotation: 0.0,
+                z: 0.0,
+                scale: Vec2::ONE,
+            },
+            anchor: None,
+            dimension,
+            em: 16.0,
+            clip: None,
+        }
+    }
+
+    fn build(parent: &ParentInfo, offset: Vec2, dimension: Vec2, rotation: f32, scale: f32) -> ParentInfo {
+        let rect = RotatedRect::construct(parent, Anchor::CENTER, Anchor::CENTER, offset, dimension,
+            Anchor::CENTER, rotation, Vec2::splat(scale), parent.rect.z + 0.01);
+        ParentInfo { rect, dimension, ..*parent }
+    }
+
+    /// Maps the local space of `rect` back to screen space.
+    fn local_frame(rect: &RotatedRect) -> Affine2 {
+        Affine2::from_scale_angle_translation(rect.scale, rect.rotation, rect.center())
+    }
+
+    #[test]
+    fn relative_to_nested_rotated_parents() {
+        let root = root(Vec2::new(800.0, 600.0));
+        let a = build(&root, Vec2::new(40.0, -30.0), Vec2::new(300.0, 200.0), 0.7, 1.5);
+        let b = build(&a, Vec2::new(-25.0, 15.0), Vec2::new(120.0, 80.0), -0.4, 2.0);
+        let c = build(&b, Vec2::new(10.0, 5.0), Vec2::new(30.0, 20.0), 1.1, 1.0);
+        for (child, parent) in [(&b, &a), (&c, &b), (&c, &a)] {
+            let relative = child.rect.relative_to(&parent.rect);
+            let composed = local_frame(&parent.rect) * relative.affine;
+            assert!(composed.abs_diff_eq(child.rect.affine, 1e-3), "{composed:?} != {:?}", child.rect.affine);
+            assert!((relative.rotation - (child.rect.rotation - parent.rect.rotation)).abs() < 1e-5);
+            assert!(relative.scale.abs_diff_eq(child.rect.scale / parent.rect.scale, 1e-5));
+        }
+    }
+
+    #[test]
+    fn relative_to_matches_offset() {
+        let root = root(Vec2::new(800.0, 600.0));
+        let a = build(&root, Vec2::new(100.0, 50.0), Vec2::new(300.0, 200.0), 0.9, 2.0);
+        let b = build(&a, Vec2::new(-25.0, 15.0), Vec2::new(120.0, 80.0), 0.3, 1.0);
+        let relative = b.rect.relative_to(&a.rect);
+        // offset and dimension are in the parent's local frame, before the parent's scale.
+        assert!(relative.center().abs_diff_eq(Vec2::new(-25.0, 15.0), 1e-3));
+        assert!(relative.half_dim().abs_diff_eq(Vec2::new(60.0, 40.0), 1e-3));
+    }
+
+    #[test]
+    fn percent_offset_rotated_parent() {
+        let root = root(Vec2::new(800.0, 600.0));
+        let parent = build(&root, Vec2::ZERO, Vec2::new(200.0, 100.0), FRAC_PI_2, 1.0);
+        let offset = Size2::percent(0.25, 0.0).as_pixels(parent.dimension, parent.em, 16.0);
+        assert_eq!(offset, Vec2::new(50.0, 0.0));
+        let child = build(&parent, offset, Vec2::new(10.0, 10.0), 0.0, 1.0);
+        // moves along the parent's rotated x axis.
+        assert!(child.rect.center().abs_diff_eq(Vec2::new(0.0, 50.0), 1e-3));
+        assert!(child.rect.relative_to(&parent.rect).center().abs_diff_eq(offset, 1e-3));
+    }
+
+    #[test]
+    fn percent_offset_rotated_scaled_parent() {
+        let root = root(Vec2::new(800.0, 600.0));
+        let grandparent = build(&root, Vec2::new(30.0, 0.0), Vec2::new(400.0, 400.0), FRAC_PI_2, 1.0);
+        let parent = build(&grandparent, Vec2::ZERO, Vec2::new(200.0, 100.0), FRAC_PI_2, 2.0);
+        let offset = Size2::percent(0.0, 0.5).as_pixels(parent.dimension, parent.em, 16.0);
+        assert_eq!(offset, Vec2::new(0.0, 50.0));
+        let child = build(&parent, offset, Vec2::new(10.0, 10.0), 0.0, 1.0);
+        // rotated by 180 degrees in total and scaled by the parent.
+        assert!(child.rect.center().abs_diff_eq(Vec2::new(30.0, -100.0), 1e-3));
+        assert!((child.rect.rotation - 2.0 * FRAC_PI_2).abs() < 1e-5);
+    }
+
+    /// A 100x50 rect with its top left corner at `(-380, 290)`, rotating about `pivot`.
+    fn pivoted(parent: &ParentInfo, pivot: Anchor, rotation: f32) -> RotatedRect {
+        RotatedRect::construct(parent, Anchor::TOP_LEFT, Anchor::TOP_LEFT, Vec2::new(20.0, -10.0),
+            Vec2::new(100.0, 50.0), pivot, rotation, Vec2::ONE, 0.01)
+    }
+
+    #[test]
+    fn pivot_without_rotation() {
+        let root = root(Vec2::new(800.0, 600.0));
+        let expected = pivoted(&root, Anchor::CENTER, 0.0);
+        assert!(expected.anchor(Anchor::TOP_LEFT).abs_diff_eq(Vec2::new(-380.0, 290.0), 1e-3));
+        for pivot in [Anchor::TOP_LEFT, Anchor::BOTTOM_RIGHT, Anchor::CENTER_LEFT, Anchor::TOP_CENTER] {
+            let rect = pivoted(&root, pivot, 0.0);
+            assert!(rect.affine.abs_diff_eq(expected.affine, 1e-3), "{:?} != {:?}", rect.affine, expected.affine);
+        }
+    }
+
+    #[test]
+    fn pivot_is_fixed_under_rotation() {
+        let root = root(Vec2::new(800.0, 600.0));
+        for pivot in [Anchor::CENTER, Anchor::TOP_LEFT, Anchor::BOTTOM_RIGHT, Anchor::CENTER_LEFT] {
+            let still = pivoted(&root, pivot, 0.0);
+            let rotated = pivoted(&root, pivot, 0.8);
+            assert!(rotated.anchor(pivot).abs_diff_eq(still.anchor(pivot), 1e-3));
+            assert!(rotated.half_dim().abs_diff_eq(still.half_dim(), 1e-3));
+            assert!((rotated.rotation - 0.8).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn compare_pivots() {
+        let root = root(Vec2::new(800.0, 600.0));
+        let center = pivoted(&root, Anchor::CENTER, FRAC_PI_2);
+        let corner = pivoted(&root, Anchor::TOP_LEFT, FRAC_PI_2);
+        // Same size and rotation, only the position differs.
+        assert!((center.rotation - corner.rotation).abs() < 1e-5);
+        assert!(center.half_dim().abs_diff_eq(corner.half_dim(), 1e-3));
+        // About the center, the center stays at `(-330, 265)`.
+        assert!(center.center().abs_diff_eq(Vec2::new(-330.0, 265.0), 1e-3));
+        // About the top left corner, the center `(50, -25)` away is rotated to `(25, 50)` away.
+        assert!(corner.anchor(Anchor::TOP_LEFT).abs_diff_eq(Vec2::new(-380.0, 290.0), 1e-3));
+        assert!(corner.center().abs_diff_eq(Vec2::new(-355.0, 340.0), 1e-3));
+    }
+}
@@ -1,4 +1,4 @@
-use bevy::{prelude::{Vec2, Resource}, reflect::Reflect};
+use bevy::{prelude::{Vec2, Component, Resource}, reflect::Reflect};
 
 /// The root font size of the window.
 ///
@@ -21,6 +21,43 @@ impl Default for RectrayRem {
     }
 }
 
+/// Insets of the window that should not be covered by UI, i.e. notches or system bars.
+///
+/// Root sprites with [`InSafeArea`] use the window shrunk by these insets as their parent,
+/// so anchors and percentage sizes are relative to the safe area.
+/// If not present or all zero, the safe area is the entire window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Resource, Reflect)]
+pub struct SafeArea {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl SafeArea {
+    pub const fn new(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self { left, right, top, bottom }
+    }
+
+    /// Returns true if no insets are set.
+    pub fn is_empty(&self) -> bool {
+        self.left == 0.0 && self.right == 0.0 && self.top == 0.0 && self.bottom == 0.0
+    }
+
+    /// Obtain the center offset and dimension of the safe area in a window of size `dimension`.
+    pub fn apply(&self, dimension: Vec2) -> (Vec2, Vec2) {
+        let offset = Vec2::new(self.left - self.right, self.bottom - self.top) / 2.0;
+        let dimension = (dimension - Vec2::new(self.left + self.right, self.top + self.bottom)).max(Vec2::ZERO);
+        (offset, dimension)
+    }
+}
+
+/// Place a root sprite in the [`SafeArea`] instead of the entire window.
+///
+/// Has no effect on non-root sprites.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component, Reflect)]
+pub struct InSafeArea;
+
 /// Set the font size of the widget.
 #[derive(Debug, Clone, Copy, Default, Reflect)]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1,11 +1,13 @@
-use bevy::math::Affine3A;
+use bevy::math::{Affine3A, Vec3A};
 use bevy::text::{TextLayoutInfo, Text2dBounds};
 use bevy::prelude::*;
 
 use bevy::sprite::Anchor as BevyAnchor;
+use bevy::render::texture::{ImageAddressMode, ImageSampler, ImageSamplerDescriptor};
 use crate::dimension::DimensionMut;
 use crate::util::ScalingFactor;
-use crate::{RotatedRect, BuildTransform, Transform2D, Opacity, IgnoreAlpha, BuildMeshTransform, Anchor, DimensionData, Dimension, Coloring};
+use crate::{RotatedRect, BuildTransform, Transform2D, Skew, Opacity, IgnoreAlpha, BuildMeshTransform, Anchor, DimensionData, Dimension, Coloring, SyncEm, SpriteFit, TileMode, TextMinSize, FollowTarget, ScaleToFit, BaselineAlign, SizeChanged};
+use bevy_defer::signals::SignalSender;
 
 
 /// Copy [`Anchor`](BevyAnchor) component's value to the [`Transform2D`] component
@@ -14,9 +16,42 @@ pub fn copy_anchor(mut query: Query<(&mut BevyAnchor, &Transform2D)>) {
 }
 
 /// Copy evaluated `TextLayoutInfo` value to our `Dimension::Copied` value
-pub fn copy_dimension_text(mut query: Query<(&TextLayoutInfo, DimensionMut)>) {
-    query.iter_mut().for_each(|(text, mut dim)| {
-        dim.update_size(|| text.logical_size)
+///
+/// If [`TextMinSize`] is present, the size is at least `TextMinSize` clamped to `Text2dBounds`.
+pub fn copy_dimension_text(mut query: Query<(&TextLayoutInfo, DimensionMut, Option<&TextMinSize>, Option<&Text2dBounds>)>) {
+    query.iter_mut().for_each(|(text, mut dim, min, bounds)| {
+        dim.update_size(|| match min {
+            Some(min) => {
+                let min = match bounds {
+                    Some(bounds) => min.0.min(bounds.size),
+                    None => min.0,
+                };
+                text.logical_size.max(min)
+            },
+            None => text.logical_size,
+        })
+    })
+}
+
+/// Compute the ascent of [`BaselineAlign`] from the fonts of a [`Text`].
+///
+/// Uses the largest ascent among sections, which is the first baseline
+/// if the first line contains every section.
+pub fn copy_text_baseline(
+    fonts: Res<Assets<Font>>,
+    mut query: Query<(&Text, &mut BaselineAlign), Or<(Changed<Text>, Changed<TextLayoutInfo>)>>
+) {
+    use ab_glyph::{Font as _, ScaleFont as _};
+    query.iter_mut().for_each(|(text, mut baseline)| {
+        let ascent = text.sections.iter()
+            .filter_map(|section| {
+                let font = fonts.get(&section.style.font)?;
+                Some(font.font.as_scaled(section.style.font_size).ascent())
+            })
+            .fold(0.0, f32::max);
+        if baseline.ascent != ascent {
+            baseline.ascent = ascent;
+        }
     })
 }
 
@@ -67,7 +102,7 @@ pub fn copy_dimension_atlas(
 
 /// Synchonize size from `Dimension` to `Sprite`
 pub fn sync_dimension_sprite(
-    mut query: Query<(&mut Sprite, &Dimension, &DimensionData)>
+    mut query: Query<(&mut Sprite, &Dimension, &DimensionData), Without<SpriteFit>>
 ) {
     //let scaling_factor = scaling_factor.get();
     query.iter_mut().for_each(|(mut sp, dimension, data)| {
@@ -77,6 +112,147 @@ pub fn sync_dimension_sprite(
     })
 }
 
+/// Copy owned dimension as sprite size, fitted with [`SpriteFit`].
+pub fn sync_dimension_sprite_fit(
+    mut query: Query<(&mut Sprite, &Handle<Image>, &SpriteFit, &Dimension, &DimensionData)>,
+    assets: Res<Assets<Image>>,
+) {
+    query.iter_mut().for_each(|(mut sp, im, fit, dimension, data)| {
+        if dimension.is_copied() {
+            return;
+        }
+        let (size, rect) = match fit {
+            SpriteFit::Fill => (data.size, sp.rect),
+            SpriteFit::Contain | SpriteFit::Cover => {
+                let Some(image) = assets.get(im).map(|x| x.size().as_vec2()) else {return};
+                if image.cmple(Vec2::ZERO).any() {
+                    return;
+                }
+                let scale = data.size / image;
+                if *fit == SpriteFit::Contain {
+                    (image * scale.min_element(), None)
+                } else {
+                    let crop = data.size / scale.max_element();
+                    let min = (image - crop) / 2.0;
+                    (data.size, Some(Rect::from_corners(min, min + crop)))
+                }
+            }
+        };
+        if sp.custom_size != Some(size) {
+            sp.custom_size = Some(size)
+        }
+        if sp.rect != rect {
+            sp.rect = rect
+        }
+    })
+}
+
+/// Compute the scale of children of sprites with [`ScaleToFit`].
+pub fn scale_to_fit(
+    mut query: Query<(&mut ScaleToFit, &RotatedRect, &Children)>,
+    children: Query<&RotatedRect>,
+) {
+    for (mut fit, rect, entities) in query.iter_mut() {
+        let inverse = rect.affine.inverse();
+        // extent from the center in this sprite's unit space, without the current scale.
+        let extent = children.iter_many(entities)
+            .flat_map(|child| [
+                Vec2::new(-0.5, -0.5), Vec2::new(0.5, -0.5),
+                Vec2::new(-0.5, 0.5), Vec2::new(0.5, 0.5),
+            ].map(|corner| child.affine.transform_point2(corner)))
+            .map(|point| inverse.transform_point2(point).abs() / fit.factor)
+            .fold(Vec2::ZERO, Vec2::max);
+        if extent.x <= 0.0 && extent.y <= 0.0 {
+            continue;
+        }
+        let scale = (Vec2::splat(0.5) / extent).min_element();
+        let scale = if fit.upscale { scale } else { scale.min(1.0) };
+        if scale.is_finite() && scale > 0.0 && fit.factor != scale {
+            fit.factor = scale;
+        }
+    }
+}
+
+/// Send [`SizeChanged`] if `DimensionData::size` changed.
+pub fn send_size_changed(
+    mut query: Query<(&mut SizeChanged, &DimensionData, SignalSender<SizeChanged>)>,
+) {
+    for (mut changed, dimension, sender) in query.iter_mut() {
+        if changed.bypass_change_detection().update(dimension.size) {
+            sender.send(dimension.size);
+        }
+    }
+}
+
+/// Move sprites with [`FollowTarget`] towards their targets.
+pub fn follow_target(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &FollowTarget, &mut Transform2D, &RotatedRect, Option<&Parent>)>,
+    rects: Query<&RotatedRect>,
+    globals: Query<&GlobalTransform>,
+) {
+    let frames = time.delta_seconds() * 60.0;
+    for (entity, follow, mut transform, rect, parent) in query.iter_mut() {
+        let target = match rects.get(follow.entity) {
+            Ok(target) => target.center(),
+            Err(_) => match globals.get(follow.entity) {
+                Ok(global) => global.translation().truncate(),
+                Err(_) => {
+                    commands.entity(entity).remove::<FollowTarget>();
+                    continue;
+                }
+            }
+        };
+        let Some(offset) = transform.offset.get_pixels() else {continue};
+        let delta = target + follow.offset - rect.anchor(transform.anchor.or(Anchor::CENTER));
+        if delta == Vec2::ZERO {
+            continue;
+        }
+        // Convert the screen space delta to the parent's space.
+        let delta = match parent.and_then(|x| rects.get(x.get()).ok()) {
+            Some(parent) => Vec2::from_angle(-parent.rotation).rotate(delta) / parent.scale,
+            None => delta,
+        };
+        let fac = 1.0 - (1.0 - follow.lerp.clamp(0.0, 1.0)).powf(frames);
+        transform.offset = (offset + delta * fac).into();
+    }
+}
+
+/// Repeat sprite images with [`TileMode`].
+///
+/// The sampler is written to the shared `Image` asset, so every sprite using
+/// the same image also repeats. Add a separate copy of the image to avoid this.
+pub fn sync_tile_mode(
+    mut query: Query<(&mut Sprite, &Handle<Image>, &TileMode)>,
+    mut assets: ResMut<Assets<Image>>,
+) {
+    query.iter_mut().for_each(|(mut sp, im, tile)| {
+        let Some(image) = assets.get(im) else {return};
+        let size = image.size().as_vec2();
+        let repeats = match &image.sampler {
+            ImageSampler::Descriptor(desc) => matches!(desc.address_mode_u, ImageAddressMode::Repeat)
+                && matches!(desc.address_mode_v, ImageAddressMode::Repeat),
+            ImageSampler::Default => false,
+        };
+        if !repeats {
+            if let Some(image) = assets.get_mut(im) {
+                let mut desc = match &image.sampler {
+                    ImageSampler::Descriptor(desc) => desc.clone(),
+                    ImageSampler::Default => ImageSamplerDescriptor::default(),
+                };
+                desc.address_mode_u = ImageAddressMode::Repeat;
+                desc.address_mode_v = ImageAddressMode::Repeat;
+                image.sampler = ImageSampler::Descriptor(desc);
+            }
+        }
+        let rect = Some(Rect::from_corners(Vec2::ZERO, size * tile.repeat));
+        if sp.rect != rect {
+            sp.rect = rect
+        }
+    })
+}
+
 /// Copy owned dimension as text bounds.
 pub fn sync_dimension_text_bounds(mut query: Query<(&mut Text2dBounds, &Dimension, &DimensionData), Without<OptOutTextBoundsSync>>) {
     query.iter_mut().for_each(|(mut sp, dimension, data)| {
@@ -93,6 +269,9 @@ pub fn sync_dimension_text_bounds(mut query: Query<(&mut Text2dBounds, &Dimensio
 pub struct OptOutTextBoundsSync;
 
 /// Opts out of synchronizing font size.
+///
+/// Takes precedence over `Interpolate<FontSize>`, which still changes
+/// the `em` of this entity and its children, but not the font size of its text.
 #[derive(Debug, Component)]
 pub struct OptOutFontSizeSync;
 
@@ -102,12 +281,18 @@ pub fn set_occluded(mut query: Query<&mut Opacity>) {
     query.iter_mut().for_each(|mut op| { op.occluded = true })
 }
 
-/// Copy em as text size.
-pub fn sync_em_text(mut query: Query<(&mut Text, &DimensionData), Without<OptOutFontSizeSync>>) {
-    query.iter_mut().for_each(|(mut sp, dimension)| {
-        if sp.as_ref().sections.iter().any(|x| x.style.font_size != dimension.em) {
-            sp.sections.iter_mut().for_each(|x| x.style.font_size = dimension.em)
+impl SyncEm for Text {
+    fn sync_em(this: &mut Mut<Self>, em: f32) {
+        if this.as_ref().sections.iter().any(|x| x.style.font_size != em) {
+            this.sections.iter_mut().for_each(|x| x.style.font_size = em)
         }
+    }
+}
+
+/// Copy em to a [`SyncEm`] component.
+pub fn sync_em<T: SyncEm>(mut query: Query<(&mut T, &DimensionData), Without<OptOutFontSizeSync>>) {
+    query.iter_mut().for_each(|(mut sp, dimension)| {
+        T::sync_em(&mut sp, dimension.em)
     })
 }
 
@@ -147,13 +332,33 @@ pub fn sync_opacity_sprite(mut query: Query<(&Coloring, &Opacity, &mut Sprite),
     })
 }
 
+/// Build an affine from scale, skew, rotation and translation, applied in that order.
+fn skewed_affine(scale: Vec2, skew: Vec2, rotation: f32, translation: Vec3) -> Affine3A {
+    if skew == Vec2::ZERO {
+        return Affine3A::from_scale_rotation_translation(
+            scale.extend(1.0),
+            Quat::from_rotation_z(rotation),
+            translation,
+        );
+    }
+    let shear = Mat2::from_cols(Vec2::new(1.0, skew.y), Vec2::new(skew.x, 1.0));
+    let mat = Mat2::from_angle(rotation) * shear * Mat2::from_diagonal(scale);
+    Affine3A::from_cols(
+        mat.x_axis.extend(0.0).into(),
+        mat.y_axis.extend(0.0).into(),
+        Vec3A::Z,
+        translation.into(),
+    )
+}
+
 pub fn build_mesh_2d_global_transform(
-    mut query: Query<(&RotatedRect, &DimensionData, &mut GlobalTransform), With<BuildMeshTransform>>
+    mut query: Query<(&RotatedRect, &DimensionData, Option<&Skew>, &mut GlobalTransform), With<BuildMeshTransform>>
 ) {
-    query.iter_mut().for_each(|(rect, dim, mut transform)|
-        *transform = Affine3A::from_scale_rotation_translation(
-            (rect.scale * dim.size).extend(1.0),
-            Quat::from_rotation_z(rect.rotation),
+    query.iter_mut().for_each(|(rect, dim, skew, mut global)|
+        *global = skewed_affine(
+            rect.scale * dim.size,
+            skew.map(|x| x.0).unwrap_or(Vec2::ZERO),
+            rect.rotation,
             rect.anchor(Anchor::CENTER).extend(rect.z)
         ).into()
     );
@@ -161,12 +366,13 @@ pub fn build_mesh_2d_global_transform(
 
 /// Generate [`GlobalTransform`] with  [`BuildTransform`].
 pub fn build_global_transform(
-    mut query: Query<(&BuildTransform, &Transform2D, &RotatedRect, &mut GlobalTransform)>,
+    mut query: Query<(&BuildTransform, &Transform2D, &RotatedRect, Option<&Skew>, &mut GlobalTransform)>,
 ) {
-    query.iter_mut().for_each(|(build, transform, rect, mut global)| {
-        *global = Affine3A::from_scale_rotation_translation(
-            rect.scale.extend(1.0),
-            Quat::from_rotation_z(rect.rotation),
+    query.iter_mut().for_each(|(build, transform, rect, skew, mut global)| {
+        *global = skewed_affine(
+            rect.scale,
+            skew.map(|x| x.0).unwrap_or(Vec2::ZERO),
+            rect.rotation,
             rect.anchor(build.0.or(transform.anchor)).extend(rect.z)
         ).into()
     });
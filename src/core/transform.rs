@@ -20,10 +20,15 @@ pub struct Transform2D{
     pub parent_anchor: Anchor,
     /// Center of `rotation` and `scale`.
     ///
+    /// This is independent of `anchor`, which only affects positioning.
     /// By default this is `Center`,
     /// If set to `Inherit`, would be the same as `anchor`.
     pub center: Anchor,
     /// Offset from parent's anchor.
+    ///
+    /// The offset is in the parent's local frame, it is rotated and scaled with the parent.
+    /// Percentages resolve against the parent's unrotated dimension,
+    /// so `[50%, 0]` always moves along the parent's own x axis.
     pub offset: Size2,
     /// Z depth, if set, this is `parent_z + z`.
     /// If not set, this is `parent_z + 0.01`.
@@ -105,6 +110,15 @@ impl Default for Transform2D {
     }
 }
 
+/// Shear factors applied around the rendering anchor, `x` shifts horizontally
+/// proportional to height, `y` shifts vertically proportional to width.
+///
+/// This only affects rendering, `RotatedRect`, hit-testing and children are not skewed.
+///
+/// Also serves as the interpolation marker, `Interpolate<Skew>` animates this component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+pub struct Skew(pub Vec2);
+
 /// Builds a `GlobalTransform` on a `Anchor`, by default `Transform2D::anchor`.
 #[derive(Debug, Clone, Component, Reflect)]
 pub struct BuildTransform(pub Anchor);
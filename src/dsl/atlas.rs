@@ -39,7 +39,8 @@ frame_extension!(pub struct AtlasBuilder {
     /// # Accepts
     ///
     /// * File name: `String` or `&str`
-    /// (requires an importer for `TextureAtlas`)
+    /// (requires an importer for `TextureAtlas`,
+    /// `.atlas.json` files are supported with the `atlas_json` feature)
     /// * Handle: `Handle<TextureAtlas>`
     /// * Struct: `TextureAtlas`
     /// * Rectangles: `Vec<Rect>`, `[Rect; N]`, `[[f32; 4]; N]`
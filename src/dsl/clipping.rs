@@ -1,6 +1,6 @@
 use bevy::{render::texture::Image,  ecs::entity::Entity, asset::Handle};
 use crate::{frame_extension, build_frame};
-use crate::widgets::clipping::ScopedCameraBundle;
+use crate::widgets::clipping::{CameraDisplay, ScopedCameraBundle};
 use crate::util::{Widget, RCommands};
 
 frame_extension!(
@@ -8,6 +8,8 @@ frame_extension!(
     pub struct CameraFrameBuilder {
         /// Render target of the camera.
         pub render_target: Option<Handle<Image>>,
+        /// Sprite displaying the render target, faded with this frame's opacity.
+        pub display: Option<Entity>,
     }
 );
 
@@ -21,6 +23,9 @@ impl Widget for CameraFrameBuilder {
             self.layer.expect("Please specify a render layer.")
         );
         commands.entity(entity).insert(bundle);
+        if let Some(display) = self.display {
+            commands.entity(entity).insert(CameraDisplay(display));
+        }
 
         (entity, entity)
     }
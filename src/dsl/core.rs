@@ -1,17 +1,20 @@
 use bevy::core::Name;
 use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::view::Visibility;
 use bevy::sprite::Sprite;
 use bevy::ecs::entity::Entity;
 use bevy::math::{Vec2, Rect};
-use bevy::text::{Text, TextSection, TextStyle, BreakLineOn, Text2dBounds, TextLayoutInfo, Font};
+use bevy::text::{Text, TextSection, TextStyle, BreakLineOn, Text2dBounds, TextLayoutInfo, Font, JustifyText};
 use bevy::render::texture::{Image, BevyDefault};
 use bevy::render::render_resource::{Extent3d, TextureDimension};
 
-use crate::{DimensionType, Transform2D, Dimension, Coloring};
-use crate::{frame_extension, Clipping, bundles::{RectrayBundle, BuildTransformBundle}, Hitbox, build_frame, layout::Container};
+use crate::{Anchor, DimensionType, Transform2D, Dimension, Coloring, SpriteFit, TileMode, TextMinSize};
+use crate::{frame_extension, Clipping, bundles::{RectrayBundle, BuildTransformBundle}, Hitbox, build_frame, layout::{Container, LayoutRange, PaddingSides}};
 
 use crate::util::{Widget, RCommands, convert::IntoAsset};
+use crate::widgets::{FontFallback, TextOutline};
 use super::Aspect;
+use crate::anim::{Interpolate, Offset, Scale};
 
 frame_extension!(pub struct FrameBuilder {});
 frame_extension!(
@@ -24,6 +27,10 @@ frame_extension!(
         pub rect: Option<Rect>,
         /// Flips the image.
         pub flip: [bool; 2],
+        /// Fit the image in an owned `dimension`, default is `Fill`.
+        pub fit: Option<SpriteFit>,
+        /// Repeat the image across `dimension`.
+        pub tile: Option<TileMode>,
     }
 );
 
@@ -41,6 +48,8 @@ frame_extension!(
         pub text: String,
         /// Handle of the font asset.
         pub font: IntoAsset<Font>,
+        /// Fallback fonts for glyphs missing from `font`, tried in order.
+        pub fallback: Vec<IntoAsset<Font>>,
         /// Bounds of the text, should not be set most of the time.
         ///
         /// If not specified this is `UNBOUNDED`.
@@ -49,6 +58,14 @@ frame_extension!(
         pub wrap: bool,
         /// Break line on, maybe use wrap instead.
         pub break_line_on: Option<BreakLineOn>,
+        /// Renders an outline behind the text.
+        pub outline: Option<TextOutline>,
+        /// Alignment of lines within the text block, independent of `anchor`.
+        ///
+        /// Only the horizontal component is used, default is left aligned.
+        pub text_anchor: Option<Anchor>,
+        /// Minimum size of the text in pixels, reserves space for empty or changing text.
+        pub min_size: Option<Vec2>,
     }
 );
 
@@ -57,6 +74,11 @@ impl Widget for FrameBuilder {
         if self.layout.is_some() && self.dimension == DimensionType::Copied {
             self.dimension = DimensionType::Dynamic;
         }
+        if self.visible == Some(false) {
+            self.opacity.opacity = 0.0;
+            self.opacity.computed_opacity = 0.0;
+            self.opacity.disabled = true;
+        }
 
         let mut base = commands.spawn_bundle(
             RectrayBundle {
@@ -67,7 +89,7 @@ impl Widget for FrameBuilder {
                     offset: self.offset,
                     rotation: self.rotation,
                     scale: self.scale.0,
-                    z: self.z
+                    z: self.z,
                 },
                 dimension: Dimension {
                     dimension: self.dimension,
@@ -79,6 +101,9 @@ impl Widget for FrameBuilder {
                 ..Default::default()
             }
         );
+        if self.visible == Some(false) {
+            base.insert(Visibility::Hidden);
+        }
         if !self.name.is_empty() {
             base.insert(Name::new(self.name));
         }
@@ -94,13 +119,32 @@ impl Widget for FrameBuilder {
             base.insert(layer);
         }
         if let Some(layout) = self.layout {
+            let range = match (self.children_range, self.max_children) {
+                (LayoutRange::All, Some(len)) => LayoutRange::Bounded { min: 0, len },
+                (range, _) => range,
+            };
             base.insert(Container {
                 layout,
                 margin: self.margin.0,
-                padding: self.padding.0,
-                range: self.children_range,
+                padding: self.padding.uniform,
+                range,
                 maximum: usize::MAX,
             });
+            if self.padding.sides != PaddingSides::default() {
+                base.insert(self.padding.sides);
+            }
+        }
+        let time = self.transition_time.unwrap_or(0.25);
+        let easing = self.transition_easing.unwrap_or_default();
+        if let Some(offset) = self.offset_to {
+            let mut interpolate = Interpolate::<Offset>::new(easing, self.offset.raw(), time);
+            interpolate.interpolate_to(offset);
+            base.insert(interpolate);
+        }
+        if let Some(scale) = self.scale_to {
+            let mut interpolate = Interpolate::<Scale>::new(easing, self.scale.0, time);
+            interpolate.interpolate_to(scale);
+            base.insert(interpolate);
         }
         let base = base.id();
         (base, base)
@@ -125,6 +169,12 @@ impl Widget for SpriteBuilder {
             Coloring::new(color),
             BuildTransformBundle::default(),
         ));
+        if let Some(tile) = self.tile {
+            frame.insert(tile);
+        }
+        if let Some(fit) = self.fit {
+            frame.insert(fit);
+        }
         (frame.id(), frame.id())
     }
 }
@@ -154,9 +204,21 @@ impl Widget for RectangleBuilder {
     }
 }
 
+/// Horizontal alignment of text from `text_anchor`, independent of the layout `anchor`.
+fn text_justify(text_anchor: Option<Anchor>) -> JustifyText {
+    match text_anchor.map(|x| x.x()) {
+        Some(x) if x > 0.0 => JustifyText::Right,
+        Some(0.0) => JustifyText::Center,
+        _ => JustifyText::Left,
+    }
+}
+
 impl Widget for TextBuilder {
     fn spawn(self, commands: &mut RCommands) -> (Entity, Entity) {
         let font = commands.load_or_default(self.font);
+        let fallback: Vec<_> = self.fallback.into_iter()
+            .map(|x| commands.load_or_default(x))
+            .collect();
         let mut frame = build_frame!(commands, self);
         let color = self.color.unwrap_or(bevy::prelude::Color::WHITE);
         frame.insert((
@@ -175,6 +237,7 @@ impl Widget for TextBuilder {
                 } else {
                     BreakLineOn::NoWrap
                 },
+                justify: text_justify(self.text_anchor),
                 ..Default::default()
             },
             match self.bounds {
@@ -186,6 +249,15 @@ impl Widget for TextBuilder {
             Coloring::new(color),
             BuildTransformBundle::default(),
         ));
+        if !fallback.is_empty() {
+            frame.insert(FontFallback::new(fallback));
+        }
+        if let Some(outline) = self.outline {
+            frame.insert(outline);
+        }
+        if let Some(min_size) = self.min_size {
+            frame.insert(TextMinSize(min_size));
+        }
         (frame.id(), frame.id())
     }
 }
@@ -217,3 +289,29 @@ macro_rules! rectangle {
     {$commands: tt {$($tt:tt)*}} =>
         {$crate::meta_dsl!($commands [$crate::dsl::builders::RectangleBuilder] {$($tt)*})};
 }
+
+#[cfg(test)]
+mod test {
+    use bevy::text::JustifyText;
+    use crate::Anchor;
+    use super::text_justify;
+
+    #[test]
+    fn text_anchor_mismatched() {
+        // alignment only depends on `text_anchor`, `anchor` is used for placement.
+        assert_eq!(text_justify(None), JustifyText::Left);
+        assert_eq!(text_justify(Some(Anchor::TOP_RIGHT)), JustifyText::Right);
+        assert_eq!(text_justify(Some(Anchor::BOTTOM_CENTER)), JustifyText::Center);
+        assert_eq!(text_justify(Some(Anchor::CENTER_LEFT)), JustifyText::Left);
+        assert_eq!(text_justify(Some(Anchor::CENTER)), JustifyText::Center);
+    }
+
+    #[test]
+    fn text_anchor_only_uses_x() {
+        for y in [-0.5, 0.0, 0.5] {
+            assert_eq!(text_justify(Some(Anchor::custom(0.5, y))), JustifyText::Right);
+            assert_eq!(text_justify(Some(Anchor::custom(-0.5, y))), JustifyText::Left);
+            assert_eq!(text_justify(Some(Anchor::custom(0.0, y))), JustifyText::Center);
+        }
+    }
+}
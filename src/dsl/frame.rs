@@ -23,10 +23,18 @@ macro_rules! frame_extension {
             /// Matched parent anchor of the sprite, default is `anchor`.
             /// Usually should not be set in idiomatic use.
             pub parent_anchor: $crate::dsl::ParentAnchor,
-            /// Center of the sprite, default is `center`.
+            /// Pivot of `rotation` and `scale`, default is `Anchor::CENTER`.
+            ///
+            /// This is independent of `anchor`, a sprite anchored at `TOP_LEFT`
+            /// still rotates about its center unless this is set.
             pub center: $crate::Anchor,
             /// Propagated opacity.
             pub opacity: $crate::Opacity,
+            /// Initial visibility of the sprite, default is visible.
+            ///
+            /// If `false`, the sprite starts transparent and disabled,
+            /// `DisplayIf` and other visibility toggles can show it later.
+            pub visible: Option<bool>,
             /// Offset of the sprite from parent's anchor.
             pub offset: $crate::Size2,
             /// Rotation of the sprite from `center`.
@@ -51,12 +59,16 @@ macro_rules! frame_extension {
             pub color: Option<$crate::bevy::render::color::Color>,
             /// Sets up which event this receives.
             ///
+            /// Accepts `EventFlags` or an array like `[EventFlags::Hover, EventFlags::LeftClick]`.
+            ///
             /// Due to this being a confusing footgun,
             /// setting event here automatically sets hitbox to `Hitbox::rect(1)` if not set manually.
             pub event: $crate::events::EventFlags,
             /// The click detection area of the sprite.
             pub hitbox: Option<$crate::Hitbox>,
             /// The render layer of the sprite.
+            ///
+            /// Add `extra: PropagateRenderLayers` to apply it to all descendants.
             pub layer: Option<$crate::bevy::render::view::RenderLayers>,
             /// Layout of the widget's children.
             ///
@@ -64,10 +76,27 @@ macro_rules! frame_extension {
             pub layout: Option<$crate::layout::LayoutObject>,
             /// Margin of the widget's layout, has no effect if widget has no layout.
             pub margin: $crate::dsl::OneOrTwo<$crate::Size2>,
-            /// Margin of the widget's layout, has no effect if widget has no layout.
-            pub padding: $crate::dsl::OneOrTwo<$crate::Size2>,
+            /// Padding of the widget's layout, has no effect if widget has no layout.
+            ///
+            /// Accepts 1 or 2 values, or `[top, right, bottom, left]`.
+            pub padding: $crate::dsl::FramePadding,
             /// Displayed range of children, default is all, has no effect if widget has no layout.
+            ///
+            /// Children outside of this range are skipped by the layout but still rendered
+            /// relative to this widget.
             pub children_range: $crate::layout::LayoutRange,
+            /// If set and `children_range` is unbounded, display at most this many children.
+            pub max_children: Option<usize>,
+            /// If set, animate `offset` to this value on spawn with `Interpolate<Offset>`.
+            ///
+            /// Uses the same units as `offset`, use `transition!` for full control.
+            pub offset_to: Option<$crate::bevy::math::Vec2>,
+            /// If set, animate `scale` to this value on spawn with `Interpolate<Scale>`.
+            pub scale_to: Option<$crate::bevy::math::Vec2>,
+            /// Duration of `offset_to` and `scale_to` in seconds, default is `0.25`.
+            pub transition_time: Option<f32>,
+            /// Easing of `offset_to` and `scale_to`, default is `Linear`.
+            pub transition_easing: Option<$crate::anim::Easing>,
             $($(#[$($attr)*])* $vis $field: $ty),*
         }
     };
@@ -84,6 +113,7 @@ macro_rules! build_frame {
                 parent_anchor: $this.parent_anchor,
                 center: $this.center,
                 opacity: $this.opacity,
+                visible: $this.visible,
                 offset: $this.offset,
                 rotation: $this.rotation,
                 scale: $this.scale,
@@ -100,6 +130,11 @@ macro_rules! build_frame {
                 margin: $this.margin,
                 padding: $this.padding,
                 children_range: $this.children_range,
+                max_children: $this.max_children,
+                offset_to: $this.offset_to,
+                scale_to: $this.scale_to,
+                transition_time: $this.transition_time,
+                transition_easing: $this.transition_easing,
             }, $commands);
             $commands.entity(entity.0)
         }
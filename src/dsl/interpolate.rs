@@ -40,6 +40,20 @@
 ///
 /// `Color` automatically uses the `color!` or `gradient!` macro's syntax.
 ///
+/// * Delay
+///
+/// Each property can be delayed by some seconds with `delay` before the mode,
+/// useful for staggered entrances.
+///
+/// ```
+/// transition!(
+///     Opacity 0.5 CubicOut init (0.0, 1.0);
+///     Offset 0.5 CubicOut delay 0.2 init (Vec2::new(0.0, -20.0), Vec2::ZERO);
+/// )
+/// ```
+///
+/// The delay is not part of the duration, it can be longer than the duration.
+///
 #[macro_export]
 macro_rules! transition {
     ($($tt:tt)*) => {
@@ -64,6 +78,19 @@ macro_rules! easing {
 #[macro_export]
 macro_rules! transition_impl {
     ({$($out: expr),*}) => {($($out),*)};
+    ({$($out: expr),*} $name:ident $time:tt $ease:tt delay $delay:tt $($rest:tt)*) => {
+        $crate::transition_impl!(@delay $delay {$($out),*} [$name $time $ease] $($rest)*)
+    };
+    (@delay $delay:tt {$($out: expr),*} [$($entry:tt)*] $(;$($rest:tt)*)?) => {
+        $crate::transition_impl!({
+            $($out,)*
+            $crate::transition_impl!({} $($entry)*).with_delay($delay as f32)
+        }
+        $($($rest)*)?)
+    };
+    (@delay $delay:tt {$($out: expr),*} [$($entry:tt)*] $head:tt $($rest:tt)*) => {
+        $crate::transition_impl!(@delay $delay {$($out),*} [$($entry)* $head] $($rest)*)
+    };
     ({$($out: expr),*} Color $time:tt $ease:tt default ($value:expr) $(;$($rest:tt)*)?) => {
         $crate::transition_impl!({
             $($out,)*
@@ -1,9 +1,40 @@
 /// this maps `macro! {}` into `macro! (ctx {})`
 ///
 /// and `child: #macro!{}` into `children: quote_syntax! (ctx macro! {})`
+///
+/// and `child: if cond {macro! {}} else {macro! {}}` into an `Option<Entity>` or `Entity`.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! inline_context {
+    (@if $ctx: tt [$($path: tt)*] [$($entity:ident)?] [$($field: ident: $value: expr),*] [$($cond: tt)*]
+            {$macro: ident ! {$($a: tt)*}} else {$macro2: ident ! {$($b: tt)*}} $(,$($rest: tt)*)?) => {
+        $crate::inline_context!(@ $ctx [$($path)*] [$($entity)?] [
+            $($field: $value,)*
+            child: if $($cond)* {
+                $macro! ($ctx {$($a)*})
+            } else {
+                $macro2! ($ctx {$($b)*})
+            }
+        ] $($($rest)*)?)
+    };
+
+    (@if $ctx: tt [$($path: tt)*] [$($entity:ident)?] [$($field: ident: $value: expr),*] [$($cond: tt)*]
+            {$macro: ident ! {$($a: tt)*}} $(,$($rest: tt)*)?) => {
+        $crate::inline_context!(@ $ctx [$($path)*] [$($entity)?] [
+            $($field: $value,)*
+            child: if $($cond)* {
+                ::std::option::Option::Some($macro! ($ctx {$($a)*}))
+            } else {
+                ::std::option::Option::None
+            }
+        ] $($($rest)*)?)
+    };
+
+    (@if $ctx: tt [$($path: tt)*] [$($entity:ident)?] [$($field: ident: $value: expr),*] [$($cond: tt)*]
+            $head: tt $($rest: tt)*) => {
+        $crate::inline_context!(@if $ctx [$($path)*] [$($entity)?] [$($field: $value),*] [$($cond)* $head] $($rest)*)
+    };
+
     (@ $ctx: tt [$($path: tt)*] [$($entity:ident)?] [$($field: ident: $value: expr),*]) => {
         $crate::meta_dsl2!($ctx [$($path)*] {$($field: $value),*} {} {} {} {} {} {$($entity)?})
     };
@@ -33,6 +64,9 @@ macro_rules! inline_context {
             child: $crate::quote_syntax!($ctx $macro { $($expr)* })
         ] $($($rest)*)?)
     };
+    ($ctx: tt [$($path: tt)*] [$($entity:ident)?] [$($field: ident: $value: expr),*] child: if $($rest: tt)*) => {
+        $crate::inline_context!(@if $ctx [$($path)*] [$($entity)?] [$($field: $value),*] [] $($rest)*)
+    };
     ($ctx: tt [$($path: tt)*] [$($entity:ident)?] [$($field: ident: $value: expr),*] $field2: ident: $macro: ident ! {$($expr: tt)*} $(,$($rest: tt)*)?) => {
         $crate::inline_context!(@ $ctx [$($path)*] [$($entity)?] [
             $($field: $value,)*
@@ -88,6 +122,13 @@ macro_rules! format_intrinsics {
             };
         }
     };
+    ($name: ident, signals) => {
+        { 
+            let _ = $crate::dsl::intrinsics::IntrinsicSignals { 
+                $name: $crate::dsl::intrinsics::SignalTuple
+            };
+        }
+    };
     ($name: ident, system) => {
         { 
             let _ = $crate::dsl::intrinsics::IntrinsicSystem { 
@@ -417,6 +458,27 @@ macro_rules! meta_dsl2 {
         )
     };
 
+    ($commands: tt [$($path: tt)*]
+        {signals: $expr: expr $(,$f: ident: $e: expr)*}
+        {$($f2: ident: $e2: expr),*}
+        {$($extras: expr),*}
+        {$($children: expr),*}
+        {$($signal: expr),*}
+        {$($system: expr),*}
+        {$($out:ident)?}
+    ) => {
+        $crate::meta_dsl2!($commands
+            [$($path)*]
+            {$($f: $e),*}
+            {$($f2: $e2),*}
+            {$($extras),*}
+            {$($children),*}
+            {$($signal,)* $expr}
+            {$($system),*}
+            {$($out)?}
+        )
+    };
+
     ($commands: tt [$($path: tt)*]
         {system: $expr: expr $(,$f: ident: $e: expr)*}
         {$($f2: ident: $e2: expr),*}
@@ -483,10 +545,15 @@ macro_rules! meta_dsl2 {
                 children,
             );
             $(
-                let signal = $first_sig $(.and($signal))*;
+                #[allow(unused_mut)]
+                let mut signal = $crate::util::IntoSignalTuple::into_signal_tuple($first_sig);
+                $($crate::util::ComponentCompose::compose(
+                    &mut signal,
+                    $crate::util::IntoSignalTuple::into_signal_tuple($signal)
+                );)*
                 $crate::util::ComposeExtension::compose(
                     &mut $commands.entity(out),
-                    signal.into_signals()
+                    signal
                 );
             )?
             $(
@@ -22,7 +22,8 @@ mod clipping;
 //mod rich_text;
 
 
-pub use util::{OneOrTwo, Scale, Aspect, WidgetWrite, ParentAnchor};
+pub use util::{OneOrTwo, FramePadding, Scale, Aspect, WidgetWrite, RecvAs, ParentAnchor};
+pub use widgets::{SegmentPosition, SegmentBackground};
 pub use crate::util::convert::{OptionEx, DslFromOptionEx, IntoAsset};
 #[doc(hidden)]
 pub use itertools::izip;
@@ -36,7 +37,7 @@ pub mod builders {
     pub use super::atlas::AtlasBuilder;
 
     pub use super::layouts::PaddingBuilder;
-    pub use super::widgets::{InputBoxBuilder, CheckButtonBuilder, RadioButtonBuilder, ButtonBuilder};
+    pub use super::widgets::{InputBoxBuilder, CheckButtonBuilder, RadioButtonBuilder, ButtonBuilder, BadgeBuilder, SegmentedBuilder, ColorPickerBuilder, WindowBuilder, ResizeHandleBuilder, TableBuilder, StepperBuilder};
     pub use super::mesh2d::{MaterialSpriteBuilder, MaterialMeshBuilder};
     pub use super::clipping::CameraFrameBuilder;
 }
@@ -141,6 +142,14 @@ pub mod intrinsics {
         pub signal: RoleSignal
     }
 
+    pub struct SignalTuple;
+
+    pub struct IntrinsicSignals {
+        /// A [`RoleSignal`](crate::sync::RoleSignal) or a tuple of them,
+        /// for adding multiple senders and receivers at once.
+        pub signals: SignalTuple
+    }
+
     pub struct AsyncSystem;
 
     pub struct IntrinsicSystem {
@@ -173,7 +182,37 @@ pub mod intrinsics {
         /// return AsyncOk;
         /// ```
         /// for an early return.
-        /// 
+        ///
+        /// To send a typed signal from an event handler, take a
+        /// [`Sender`](crate::defer::signals::Sender) of that signal as a parameter.
+        /// `send` returns a function that writes the value when called.
+        ///
+        /// ```
+        /// signal_ids!(pub ClickCount: usize);
+        ///
+        /// #[derive(Component)]
+        /// struct Clicks(usize);
+        ///
+        /// let (click_send, _) = signal();
+        /// let (count_send, count_recv) = signal();
+        /// button!(commands {
+        ///     on_click: click_send,
+        ///     extra: Clicks(0),
+        ///     signal: sender::<ClickCount>(count_send),
+        ///     system: |click: Sender<ButtonClick>, count: Sender<ClickCount>, clicks: Ac<Clicks>| {
+        ///         click.recv().await;
+        ///         let n = clicks.set(|x| { x.0 += 1; x.0 }).await?;
+        ///         count.send(n)();
+        ///     }
+        /// });
+        /// text!(commands {
+        ///     signal: receiver::<ClickCount>(count_recv),
+        ///     system: |count: Receiver<ClickCount>, text: Ac<Text>| {
+        ///         let n = count.recv().await;
+        ///         text.set(move |text| format_widget!(text, "Clicked {} times", n)).await?;
+        ///     }
+        /// });
+        /// ```
         pub system: AsyncSystem
     }
 }
\ No newline at end of file
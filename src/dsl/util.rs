@@ -4,11 +4,13 @@ use bevy::ecs::component::Component;
 use bevy::ecs::world::Mut;
 use bevy::math::Vec2;
 use bevy::text::Text;
-use bevy_defer::{AsyncComponent, AsyncResult};
-use crate::layout::{Layout, LayoutObject};
+use bevy_defer::{AsObject, AsyncComponent, AsyncResult, Object};
+use bevy_defer::signals::{Sender, SignalId};
+use crate::layout::{Layout, LayoutObject, PaddingSides};
 use crate::widgets::TextFragment;
 use crate::widgets::inputbox::InputBox;
 use crate::{Hitbox, HitboxShape, Anchor, SizeUnit, Size};
+use crate::events::EventFlags;
 use crate::{Size2, FontSize, layout::Alignment, layout::LayoutDir};
 
 use super::DslFrom;
@@ -83,9 +85,13 @@ pub enum SpacialConst {
     BottomToTop,
 }
 
-impl DslFrom<SpacialConst> for Anchor {
-    fn dfrom(value: SpacialConst) -> Self {
-        match value {
+impl SpacialConst {
+    /// Convert to an [`Anchor`].
+    ///
+    /// Directions are converted to the edge they start from,
+    /// i.e. `LeftToRight` is `CenterLeft`.
+    pub const fn as_anchor(self) -> Anchor {
+        match self {
             SpacialConst::TopLeft => Anchor::TOP_LEFT,
             SpacialConst::TopCenter => Anchor::TOP_CENTER,
             SpacialConst::TopRight => Anchor::TOP_RIGHT,
@@ -99,29 +105,34 @@ impl DslFrom<SpacialConst> for Anchor {
             SpacialConst::Bottom => Anchor::BOTTOM_CENTER,
             SpacialConst::Left => Anchor::CENTER_LEFT,
             SpacialConst::Right => Anchor::CENTER_RIGHT,
-            c => panic!("{:?} is not an Anchor.", c),
+            SpacialConst::LeftToRight => Anchor::CENTER_LEFT,
+            SpacialConst::RightToLeft => Anchor::CENTER_RIGHT,
+            SpacialConst::TopToBottom => Anchor::TOP_CENTER,
+            SpacialConst::BottomToTop => Anchor::BOTTOM_CENTER,
         }
     }
 }
 
+/// Construct a custom [`Anchor`], `(-0.5, -0.5)` is bottom left and `(0.5, 0.5)` is top right.
+pub const fn anchor(x: f32, y: f32) -> Anchor {
+    Anchor::custom(x, y)
+}
+
+impl DslFrom<SpacialConst> for Anchor {
+    fn dfrom(value: SpacialConst) -> Self {
+        value.as_anchor()
+    }
+}
+
 impl DslFrom<SpacialConst> for Option<Anchor> {
     fn dfrom(value: SpacialConst) -> Self {
-        Some(match value {
-            SpacialConst::TopLeft => Anchor::TOP_LEFT,
-            SpacialConst::TopCenter => Anchor::TOP_CENTER,
-            SpacialConst::TopRight => Anchor::TOP_RIGHT,
-            SpacialConst::CenterLeft => Anchor::CENTER_LEFT,
-            SpacialConst::Center => Anchor::CENTER,
-            SpacialConst::CenterRight => Anchor::CENTER_RIGHT,
-            SpacialConst::BottomLeft => Anchor::BOTTOM_LEFT,
-            SpacialConst::BottomCenter => Anchor::BOTTOM_CENTER,
-            SpacialConst::BottomRight => Anchor::BOTTOM_RIGHT,
-            SpacialConst::Top => Anchor::TOP_CENTER,
-            SpacialConst::Bottom => Anchor::BOTTOM_CENTER,
-            SpacialConst::Left => Anchor::CENTER_LEFT,
-            SpacialConst::Right => Anchor::CENTER_RIGHT,
-            c => panic!("{:?} is not an Anchor.", c),
-        })
+        Some(value.as_anchor())
+    }
+}
+
+impl DslFrom<Vec2> for Anchor {
+    fn dfrom(value: Vec2) -> Self {
+        Anchor::new(value)
     }
 }
 
@@ -129,22 +140,7 @@ type BevyAnchor = bevy::sprite::Anchor;
 
 impl DslInto<BevyAnchor> for SpacialConst {
     fn dinto(self) -> BevyAnchor {
-        match self {
-            SpacialConst::TopLeft => BevyAnchor::TopLeft,
-            SpacialConst::TopCenter => BevyAnchor::TopCenter,
-            SpacialConst::TopRight => BevyAnchor::TopRight,
-            SpacialConst::CenterLeft => BevyAnchor::CenterLeft,
-            SpacialConst::Center => BevyAnchor::Center,
-            SpacialConst::CenterRight => BevyAnchor::CenterRight,
-            SpacialConst::BottomLeft => BevyAnchor::BottomLeft,
-            SpacialConst::BottomCenter => BevyAnchor::BottomCenter,
-            SpacialConst::BottomRight => BevyAnchor::BottomRight,
-            SpacialConst::Top => BevyAnchor::TopCenter,
-            SpacialConst::Bottom => BevyAnchor::BottomCenter,
-            SpacialConst::Left => BevyAnchor::CenterLeft,
-            SpacialConst::Right => BevyAnchor::CenterRight,
-            c => panic!("{:?} is not an Anchor.", c),
-        }
+        self.as_anchor().into()
     }
 }
 
@@ -326,6 +322,56 @@ impl DslInto<OneOrTwo<Size2>> for Size {
     }
 }
 
+/// Padding of a frame, accepts 1 or 2 values, or `[top, right, bottom, left]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FramePadding {
+    pub uniform: Size2,
+    pub sides: PaddingSides,
+}
+
+impl<A> DslConvert<FramePadding, 'p'> for A where A: DslInto<OneOrTwo<Size2>> {
+    fn parse(self) -> FramePadding {
+        FramePadding {
+            uniform: self.dinto().0,
+            sides: PaddingSides::default(),
+        }
+    }
+
+    fn sealed(_: SealToken) {}
+}
+
+impl<A> DslConvert<FramePadding, 'P'> for A where A: DslInto<Size2> {
+    fn parse(self) -> FramePadding {
+        FramePadding {
+            uniform: self.dinto(),
+            sides: PaddingSides::default(),
+        }
+    }
+
+    fn sealed(_: SealToken) {}
+}
+
+impl<T: DslInto<Size>> DslFrom<[T; 4]> for FramePadding {
+    fn dfrom([top, right, bottom, left]: [T; 4]) -> Self {
+        FramePadding {
+            uniform: Size2::ZERO,
+            sides: PaddingSides::new(top.dinto(), right.dinto(), bottom.dinto(), left.dinto()),
+        }
+    }
+}
+
+impl DslFrom<i32> for Size {
+    fn dfrom(value: i32) -> Self {
+        Size::new(SizeUnit::Pixels, value as f32)
+    }
+}
+
+impl DslFrom<f32> for Size {
+    fn dfrom(value: f32) -> Self {
+        Size::new(SizeUnit::Pixels, value)
+    }
+}
+
 /// A `OneOrTwo<Vec2>` with default value `1`.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Scale(pub Vec2);
@@ -494,6 +540,18 @@ macro_rules! size2 {
     };
 }
 
+impl<const N: usize> DslFrom<[EventFlags; N]> for EventFlags {
+    fn dfrom(value: [EventFlags; N]) -> Self {
+        value.into_iter().fold(EventFlags::default(), |a, b| a | b)
+    }
+}
+
+impl DslFrom<&[EventFlags]> for EventFlags {
+    fn dfrom(value: &[EventFlags]) -> Self {
+        value.iter().fold(EventFlags::default(), |a, b| a | *b)
+    }
+}
+
 /// Format trait for a widget.
 pub trait WidgetWrite {
     fn write(self, s: String);
@@ -551,6 +609,25 @@ impl<C: Component> WidgetWriteAsync for AsyncComponent<'_, C> where for<'t> &'t
     }
 }
 
+/// Receive a signal carrying an [`Object`], like `ButtonClick`, as a concrete type.
+///
+/// Useful for reading a button's `Payload` in an async system.
+/// [`Receiver`](bevy_defer::signals::Receiver) has an inherent `recv_as` that skips values of other types.
+#[allow(async_fn_in_trait)]
+pub trait RecvAs {
+    /// Receive the next value as `T`, returns `None` if it has a different type.
+    ///
+    /// Since this consumes the parameter, return from the async system on `None`
+    /// to skip the value, the system receives the next value on its next run.
+    async fn recv_as<T: AsObject>(self) -> Option<T>;
+}
+
+impl<S: SignalId<Data = Object>> RecvAs for Sender<S> {
+    async fn recv_as<T: AsObject>(self) -> Option<T> {
+        self.recv().await.get::<T>()
+    }
+}
+
 /// Write to a text widget component using `format!` syntax.
 ///
 /// The component must implement [`WidgetWrite`].
@@ -1,21 +1,32 @@
+use std::time::Duration;
 use bevy::ecs::entity::Entity;
 use bevy::hierarchy::BuildChildren;
+use bevy::math::Vec2;
 
+use bevy::render::{color::Color, texture::Image};
+use bevy::asset::Handle;
+use bevy::sprite::Sprite;
 use bevy::text::Font;
 use bevy::window::CursorIcon;
 use bevy_defer::Object;
 use bevy_defer::signals::{TypedSignal, Signals};
 use crate::util::ComposeExtension;
 use crate::widgets::TextFragment;
-use crate::widgets::button::{Payload, Button, CheckButton, RadioButton, RadioButtonCancel, ButtonClick, ToggleChange};
-use crate::widgets::util::{SetCursor, PropagateFocus};
-use crate::{build_frame, Anchor, rectangle, Size, size};
+use crate::widgets::button::{Payload, Button, CheckButton, CheckButtonState, RadioButton, RadioButtonCancel, ButtonClick, ToggleChange, EventCooldown, RadioButtonChange, RepeatClick};
+use crate::widgets::util::{DisplayIf, SetCursor, PropagateFocus};
+use crate::{build_frame, Anchor, Coloring, DimensionType, frame, rectangle, sprite, text, Size, Size2, SizeUnit, size};
+use crate::bundles::BuildTransformBundle;
+use crate::layout::{BoundsLayout, LayoutControl, StackLayout, TableLayout};
+use crate::widgets::misc::{Badge, BadgeState, BadgeText, TableSort, TableHeaderClick, Stepper, StepperStep};
+use crate::widgets::color_picker::{ColorPicker, ColorPickerHue, ColorPickerSV, ColorPickerAlpha, ColorPickerHueDisplay, ColorPickerHandleInit};
+use crate::widgets::drag::{Dragging, DragState, RaiseOnDrag, ResizeHandle};
+use crate::widgets::constraints::{PositionFac, SharedPosition};
 use crate::events::EventFlags;
 use crate::frame_extension;
-use crate::widgets::inputbox::{InputOverflow, InputBoxText, TextSubmit, TextChange};
+use crate::widgets::inputbox::{InputOverflow, InputBoxText, InputStep, InputPreprocessor, TextSubmit, TextChange};
 use crate::widgets::inputbox::{InputBox, InputBoxCursorBar, InputBoxCursorArea};
 
-use crate::util::{Widget, RCommands, convert::IntoAsset};
+use crate::util::{Widget, WidgetBuilder, RCommands, signal, convert::IntoAsset};
 
 frame_extension!(
     pub struct InputBoxBuilder {
@@ -30,12 +41,19 @@ frame_extension!(
         pub overflow: InputOverflow,
         /// Sets the CursorIcon when hovering this button, default is `Text`
         pub cursor_icon: Option<CursorIcon>,
+        /// If set, the mouse wheel increments or decrements a numeric value.
+        pub step: Option<InputStep>,
+        /// If set, transforms or vetoes typed characters.
+        pub preprocessor: Option<InputPreprocessor>,
     }
 );
 
 impl Widget for InputBoxBuilder {
     fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
         self.event |= EventFlags::Hover|EventFlags::DoubleClick|EventFlags::LeftDrag|EventFlags::ClickOutside;
+        if self.step.is_some() {
+            self.event |= EventFlags::MouseWheel;
+        }
         let font = commands.load_or_default(self.font);
 
         let mut entity = build_frame!(commands, self);
@@ -50,6 +68,12 @@ impl Widget for InputBoxBuilder {
                 icon: self.cursor_icon.unwrap_or(CursorIcon::Text),
             },
         ));
+        if let Some(step) = self.step {
+            entity.insert(step);
+        }
+        if let Some(preprocessor) = self.preprocessor.take() {
+            entity.insert(preprocessor);
+        }
         entity.compose2(
             self.on_change.map(Signals::from_sender::<TextChange>),
             self.on_submit.map(Signals::from_sender::<TextSubmit>)
@@ -94,6 +118,8 @@ frame_extension!(
         pub on_click: Option<TypedSignal<Object>>,
         /// If set, `submit` sends its contents.
         pub payload: Option<Payload>,
+        /// If set, ignore clicks within this duration of the last click.
+        pub cooldown: Option<Duration>,
     }
 );
 
@@ -112,6 +138,9 @@ impl Widget for ButtonBuilder {
         if let Some(payload) = self.payload  {
             entity.insert(payload);
         }
+        if let Some(cooldown) = self.cooldown {
+            entity.insert(EventCooldown::new(cooldown));
+        }
         if let Some(click) = self.on_click {
             entity.compose(Signals::from_sender::<ButtonClick>(click));
         }
@@ -173,6 +202,9 @@ frame_extension!(
         pub value: Option<Payload>,
         /// Sends a signal whenever the button is clicked.
         pub on_click: Option<TypedSignal<Object>>,
+        /// Sends the new selection when this button changes it,
+        /// `Object::NONE` if cancelled.
+        pub on_change: Option<TypedSignal<Object>>,
     }
 );
 
@@ -196,6 +228,9 @@ impl Widget for RadioButtonBuilder {
         if let Some(click) = self.on_click {
             entity.compose(Signals::from_sender::<ButtonClick>(click));
         }
+        if let Some(change) = self.on_change {
+            entity.compose(Signals::from_sender::<RadioButtonChange>(change));
+        }
         let entity = entity.id();
         (entity, entity)
     }
@@ -296,3 +331,743 @@ macro_rules! radio_button {
     {$commands: tt {$($tt:tt)*}} =>
         {$crate::meta_dsl!($commands [$crate::dsl::builders::RadioButtonBuilder] {$($tt)*})};
 }
+
+frame_extension!(
+    pub struct BadgeBuilder {
+        /// Count displayed on the badge, hidden if zero.
+        pub count: usize,
+        /// Maximum displayed count, larger values display as `{max}+`, default is `99`.
+        pub max: Option<usize>,
+        /// Background image of the badge, default is a rectangle of `color`.
+        pub sprite: IntoAsset<Image>,
+        /// Font of the text.
+        pub font: IntoAsset<Font>,
+        /// Color of the text, default is white.
+        pub text_color: Option<Color>,
+        /// Receives a new count.
+        pub on_count: Option<TypedSignal<usize>>,
+    }
+);
+
+impl Widget for BadgeBuilder {
+    fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
+        if self.parent_anchor.0.is_inherit() {
+            self.parent_anchor = Anchor::TOP_RIGHT.into();
+        }
+        if self.layout.is_none() {
+            self.layout = Some(BoundsLayout::PADDING.into());
+        }
+        let badge = Badge::new(self.count).with_max(self.max.unwrap_or(99));
+        if badge.count == 0 {
+            self.opacity.opacity = 0.0;
+            self.opacity.disabled = true;
+        }
+        let color = self.color.unwrap_or(Color::RED);
+        let sprite = commands.load_or_default(self.sprite);
+        let font = commands.load_or_default(self.font);
+        let text_color = self.text_color.unwrap_or(Color::WHITE);
+        let on_count = self.on_count.take();
+        let mut entity = build_frame!(commands, self);
+        entity.insert((
+            badge,
+            if badge.count > 0 { BadgeState::NonZero } else { BadgeState::Zero },
+            DisplayIf(BadgeState::NonZero),
+            Sprite {
+                color,
+                ..Default::default()
+            },
+            sprite,
+            Coloring::new(color),
+            BuildTransformBundle::default(),
+        ));
+        if let Some(signal) = on_count {
+            entity.compose(Signals::from_receiver::<Badge>(signal));
+        }
+        let entity = entity.id();
+        let text = text!(commands {
+            text: badge.text(),
+            font: font,
+            color: text_color,
+            extra: BadgeText,
+        });
+        commands.entity(entity).add_child(text);
+        (entity, entity)
+    }
+}
+
+/// Construct a count badge anchored to a corner of its parent,
+/// default is the top right corner.
+/// The underlying struct is [`BadgeBuilder`].
+///
+/// The badge sizes to its text, use `padding` to add space around the text.
+#[macro_export]
+macro_rules! badge {
+    {$commands: tt {$($tt:tt)*}} =>
+        {$crate::meta_dsl!($commands [$crate::dsl::builders::BadgeBuilder] {$($tt)*})};
+}
+
+/// Position of a segment in a [`segmented!`](crate::segmented) control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentPosition {
+    /// The only segment, all corners are outer corners.
+    Only,
+    /// The first segment, left corners are outer corners.
+    First,
+    Middle,
+    /// The last segment, right corners are outer corners.
+    Last,
+}
+
+/// Argument of [`SegmentedBuilder::background`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentBackground {
+    pub position: SegmentPosition,
+    /// `color` or `selected_color` of the control.
+    pub color: Color,
+    /// If set, this background is displayed when the segment is selected.
+    pub selected: bool,
+}
+
+frame_extension!(
+    pub struct SegmentedBuilder {
+        /// Labels of the segments.
+        pub items: Vec<String>,
+        /// Payloads of the segments, default is the index of the segment as `usize`.
+        pub values: Vec<Payload>,
+        /// The shared context of the segments, created if not specified.
+        pub context: Option<RadioButton>,
+        /// Dimension of each segment, default is `[4em, 2em]`.
+        pub segment: Option<Size2>,
+        /// Font of the labels.
+        pub font: IntoAsset<Font>,
+        /// Color of the labels, default is white.
+        pub text_color: Option<Color>,
+        /// Background color of the selected segment, `color` is used for the rest.
+        pub selected_color: Option<Color>,
+        /// If set, builds the backgrounds of each segment instead of spawning `rectangle`s,
+        /// the result should fill the segment.
+        ///
+        /// Use this to round the outer corners with a custom material.
+        pub background: Option<WidgetBuilder<SegmentBackground>>,
+        /// Sends the selected `Payload` when a segment is clicked.
+        pub on_change: Option<TypedSignal<Object>>,
+    }
+);
+
+impl Widget for SegmentedBuilder {
+    fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
+        if self.layout.is_none() {
+            self.layout = Some(StackLayout::HSTACK.into());
+        }
+        let context = self.context.take().unwrap_or_default();
+        let items = std::mem::take(&mut self.items);
+        let mut values = std::mem::take(&mut self.values).into_iter();
+        let segment = self.segment.unwrap_or(Size2::em(4.0, 2.0));
+        let color = self.color.unwrap_or(Color::GRAY);
+        let selected_color = self.selected_color.unwrap_or(Color::WHITE);
+        let text_color = self.text_color.unwrap_or(Color::WHITE);
+        let font = commands.load_or_default(std::mem::take(&mut self.font));
+        let on_change = self.on_change.take();
+        let background_fn = self.background.take();
+        self.color = None;
+        let entity = build_frame!(commands, self).id();
+        let count = items.len();
+        for (index, item) in items.into_iter().enumerate() {
+            let value = values.next().unwrap_or_else(|| Payload::new(index));
+            let position = match index {
+                _ if count == 1 => SegmentPosition::Only,
+                0 => SegmentPosition::First,
+                _ if index + 1 == count => SegmentPosition::Last,
+                _ => SegmentPosition::Middle,
+            };
+            let (background, selected) = match &background_fn {
+                Some(background_fn) => {
+                    let background = commands.spawn_fn(background_fn, SegmentBackground {
+                        position,
+                        color,
+                        selected: false,
+                    });
+                    let selected = commands.spawn_fn(background_fn, SegmentBackground {
+                        position,
+                        color: selected_color,
+                        selected: true,
+                    });
+                    commands.entity(background).insert(DisplayIf(CheckButtonState::Unchecked));
+                    commands.entity(selected).insert(DisplayIf(CheckButtonState::Checked));
+                    (background, selected)
+                },
+                None => (
+                    rectangle!(commands {
+                        dimension: DimensionType::Owned(Size2::FULL),
+                        color: color,
+                        extra: DisplayIf(CheckButtonState::Unchecked),
+                    }),
+                    rectangle!(commands {
+                        dimension: DimensionType::Owned(Size2::FULL),
+                        color: selected_color,
+                        extra: DisplayIf(CheckButtonState::Checked),
+                    }),
+                ),
+            };
+            let label = text!(commands {
+                text: item,
+                font: font.clone(),
+                color: text_color,
+                z: 0.01,
+            });
+            let (button, _) = RadioButtonBuilder {
+                dimension: DimensionType::Owned(segment),
+                context: Some(context.clone()),
+                value: Some(value),
+                on_click: on_change.clone(),
+                ..Default::default()
+            }.spawn(commands);
+            commands.entity(button).push_children(&[background, selected, label]);
+            commands.entity(entity).add_child(button);
+        }
+        (entity, entity)
+    }
+}
+
+/// Construct a row of connected `radio_button`s sharing a context,
+/// with the selected segment highlighted.
+/// The underlying struct is [`SegmentedBuilder`].
+///
+/// The selected segment's `Payload` is sent through `on_change`
+/// and stored in the `RadioButton` context.
+///
+/// Segments are square by default, this crate provides no rounded shapes.
+/// To round only the outer corners, build the backgrounds with `background`
+/// using a rounded material, [`SegmentPosition`] tells which corners are outer corners.
+#[macro_export]
+macro_rules! segmented {
+    {$commands: tt {$($tt:tt)*}} =>
+        {$crate::meta_dsl!($commands [$crate::dsl::builders::SegmentedBuilder] {$($tt)*})};
+}
+
+frame_extension!(
+    pub struct StepperBuilder {
+        /// Initial value, clamped to the range.
+        pub value: f32,
+        /// Minimum value, default is unbounded.
+        pub min: Option<f32>,
+        /// Maximum value, default is unbounded.
+        pub max: Option<f32>,
+        /// Increment of the buttons, default is `1`.
+        pub step: Option<f32>,
+        /// Number of decimal places displayed.
+        pub precision: usize,
+        /// Font of the value and the buttons.
+        pub font: IntoAsset<Font>,
+        /// Color of the value and the buttons' labels, default is white.
+        pub text_color: Option<Color>,
+        /// Width of the `input_box`, default is `4em`.
+        pub width: Option<Size>,
+        /// If set, holding a button repeats it.
+        pub repeat: Option<RepeatClick>,
+        /// Sends the new value as `f32` whenever it changes.
+        pub on_change: Option<TypedSignal<f32>>,
+    }
+);
+
+impl Widget for StepperBuilder {
+    fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
+        if self.layout.is_none() {
+            self.layout = Some(StackLayout::HSTACK.into());
+        }
+        let mut stepper = Stepper::new(self.value, self.step.unwrap_or(1.0))
+            .with_range(self.min.unwrap_or(f32::MIN), self.max.unwrap_or(f32::MAX))
+            .with_precision(self.precision);
+        stepper.value = stepper.clamp(stepper.value);
+        let text_color = self.text_color.unwrap_or(Color::WHITE);
+        let button_color = self.color.take();
+        let font = commands.load_or_default(std::mem::take(&mut self.font));
+        let width = self.width.unwrap_or(Size::new(SizeUnit::Em, 4.0));
+        let repeat = self.repeat.take();
+        let on_change = self.on_change.take();
+        let (step_send, step_recv) = signal::<Object, _>();
+        let (submit_send, submit_recv) = signal::<String, _>();
+        let mut entity = build_frame!(commands, self);
+        entity.insert(stepper);
+        let mut signals = Signals::from_receiver::<StepperStep>(step_recv);
+        signals.add_receiver::<TextSubmit>(submit_recv);
+        entity.compose(signals);
+        if let Some(on_change) = on_change {
+            entity.compose(Signals::from_sender::<Stepper>(on_change));
+        }
+        let entity = entity.id();
+        let step_button = |commands: &mut RCommands, label: &str, delta: f32| {
+            let (button, _) = ButtonBuilder {
+                dimension: DimensionType::Owned(Size2::em(1.5, 1.5)),
+                payload: Some(Payload::new(delta)),
+                on_click: Some(step_send.clone()),
+                ..Default::default()
+            }.spawn(commands);
+            if let Some(repeat) = repeat {
+                commands.entity(button).insert(repeat);
+            }
+            if let Some(color) = button_color {
+                let background = rectangle!(commands {
+                    dimension: DimensionType::Owned(Size2::FULL),
+                    color: color,
+                });
+                commands.entity(button).add_child(background);
+            }
+            let label = text!(commands {
+                text: label,
+                font: font.clone(),
+                color: text_color,
+                z: 0.01,
+            });
+            commands.entity(button).add_child(label);
+            button
+        };
+        let minus = step_button(commands, "-", -1.0);
+        let plus = step_button(commands, "+", 1.0);
+        let cursor_bar = rectangle!(commands {
+            color: text_color,
+            z: 0.1,
+            dimension: DimensionType::Owned(Size2::new(Size::new(SizeUnit::Pixels, 2.0), Size::new(SizeUnit::Em, 1.0))),
+        });
+        let cursor_area = rectangle!(commands {
+            color: text_color.with_a(0.5),
+            z: -0.1,
+            dimension: DimensionType::Owned(Size2::new(Size::new(SizeUnit::Pixels, 12.0), Size::new(SizeUnit::Em, 1.0))),
+        });
+        let (input, _) = InputBoxBuilder {
+            dimension: DimensionType::Owned(Size2::new(width, Size::new(SizeUnit::Em, 1.5))),
+            text: stepper.text(),
+            font: IntoAsset::Handle(font),
+            color: Some(text_color),
+            cursor_bar: Some(cursor_bar),
+            cursor_area: Some(cursor_area),
+            on_submit: Some(submit_send),
+            preprocessor: Some(InputPreprocessor::numeric()),
+            ..Default::default()
+        }.spawn(commands);
+        commands.entity(entity).push_children(&[minus, input, plus]);
+        (entity, entity)
+    }
+}
+
+/// Construct a `stepper`, a numeric `input_box` between a decrement and an increment `button`.
+/// The underlying struct is [`StepperBuilder`].
+///
+/// The value is stored in the [`Stepper`] component, clamped to `min` and `max`
+/// and sent through `on_change`.
+/// Only numeric characters can be typed, values are parsed and clamped
+/// on submit or when focus is lost, invalid text is reverted.
+#[macro_export]
+macro_rules! stepper {
+    {$commands: tt {$($tt:tt)*}} =>
+        {$crate::meta_dsl!($commands [$crate::dsl::builders::StepperBuilder] {$($tt)*})};
+}
+
+frame_extension!(
+    pub struct ColorPickerBuilder {
+        /// Initial value of the picker.
+        pub default: Option<ColorPicker>,
+        /// Image of the saturation/value square, tinted with the current hue.
+        pub square: IntoAsset<Image>,
+        /// Image of the hue bar.
+        pub hue_bar: IntoAsset<Image>,
+        /// Image of the alpha bar, if set, adds an alpha slider.
+        pub alpha_bar: IntoAsset<Image>,
+        /// Image of the draggable handles.
+        pub handle: IntoAsset<Image>,
+        /// Size of the draggable handles, default is `[1em, 1em]`.
+        pub handle_size: Option<Size2>,
+        /// Sends the picked color on change.
+        pub on_change: Option<TypedSignal<Color>>,
+    }
+);
+
+impl Widget for ColorPickerBuilder {
+    fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
+        let has_alpha = !matches!(self.alpha_bar, IntoAsset::None);
+        let square = commands.load_or_default(std::mem::take(&mut self.square));
+        let hue_bar = commands.load_or_default(std::mem::take(&mut self.hue_bar));
+        let alpha_bar = commands.load_or_default(std::mem::take(&mut self.alpha_bar));
+        let handle = commands.load_or_default(std::mem::take(&mut self.handle));
+        let handle_size = DimensionType::Owned(self.handle_size.unwrap_or(Size2::em(1.0, 1.0)));
+        let picker = self.default.take().unwrap_or_default();
+        let on_change = self.on_change.take();
+        let mut entity = build_frame!(commands, self);
+        entity.insert(picker);
+        if let Some(signal) = on_change {
+            entity.compose(Signals::from_sender::<ColorPicker>(signal));
+        }
+        let entity = entity.id();
+
+        let (hue_send, hue_recv) = signal::<f32, _>();
+        let (sv_send, sv_recv) = signal::<Vec2, _>();
+        let square = sprite!(commands {
+            anchor: Anchor::TOP_CENTER,
+            dimension: DimensionType::Owned(Size2::percent(1.0, 0.75)),
+            sprite: square,
+            color: picker.hue_color(),
+            extra: ColorPickerHueDisplay,
+        });
+        let sv_handle = sprite!(commands {
+            anchor: Anchor::BOTTOM_LEFT,
+            dimension: handle_size,
+            sprite: handle.clone(),
+            event: EventFlags::LeftDrag,
+            z: 0.01,
+            extra: Dragging::BOTH,
+        });
+        commands.entity(sv_handle)
+            .insert(ColorPickerHandleInit(Vec2::new(picker.saturation, picker.value)))
+            .compose(Signals::from_sender::<SharedPosition>(sv_send));
+        commands.entity(square).add_child(sv_handle);
+
+        let hue_bar = sprite!(commands {
+            anchor: Anchor::BOTTOM_CENTER,
+            dimension: DimensionType::Owned(Size2::percent(1.0, 0.1)),
+            offset: if has_alpha {Size2::percent(0.0, 0.125)} else {Size2::ZERO},
+            sprite: hue_bar,
+        });
+        let hue_handle = sprite!(commands {
+            anchor: Anchor::CENTER_LEFT,
+            dimension: handle_size,
+            sprite: handle.clone(),
+            event: EventFlags::LeftDrag,
+            z: 0.01,
+            extra: Dragging::X,
+        });
+        commands.entity(hue_handle)
+            .insert(ColorPickerHandleInit(Vec2::new(picker.hue.rem_euclid(360.0) / 360.0, f32::NAN)))
+            .compose(Signals::from_sender::<PositionFac>(hue_send));
+        commands.entity(hue_bar).add_child(hue_handle);
+        commands.entity(entity).push_children(&[square, hue_bar]);
+
+        let mut signals = Signals::from_receiver::<ColorPickerHue>(hue_recv);
+        signals.add_receiver::<ColorPickerSV>(sv_recv);
+        if has_alpha {
+            let (alpha_send, alpha_recv) = signal::<f32, _>();
+            let alpha_bar = sprite!(commands {
+                anchor: Anchor::BOTTOM_CENTER,
+                dimension: DimensionType::Owned(Size2::percent(1.0, 0.1)),
+                sprite: alpha_bar,
+            });
+            let alpha_handle = sprite!(commands {
+                anchor: Anchor::CENTER_RIGHT,
+                dimension: handle_size,
+                sprite: handle,
+                event: EventFlags::LeftDrag,
+                z: 0.01,
+                extra: Dragging::X,
+            });
+            commands.entity(alpha_handle)
+                .insert(ColorPickerHandleInit(Vec2::new(picker.alpha, f32::NAN)))
+                .compose(Signals::from_sender::<PositionFac>(alpha_send));
+            commands.entity(alpha_bar).add_child(alpha_handle);
+            commands.entity(entity).add_child(alpha_bar);
+            signals.add_receiver::<ColorPickerAlpha>(alpha_recv);
+        }
+        commands.entity(entity).compose(signals);
+        (entity, entity)
+    }
+}
+
+/// Construct a color picker from a saturation/value square, a hue bar
+/// and an optional alpha bar. The underlying struct is [`ColorPickerBuilder`].
+///
+/// This crate provides no shaders, the images of the square and bars should be
+/// supplied by the user, the square is tinted with the current hue.
+/// Handles start at the position of `default`, which is black if not specified.
+#[macro_export]
+macro_rules! color_picker {
+    {$commands: tt {$($tt:tt)*}} =>
+        {$crate::meta_dsl!($commands [$crate::dsl::builders::ColorPickerBuilder] {$($tt)*})};
+}
+
+frame_extension!(
+    pub struct WindowBuilder {
+        /// Title displayed on the title bar.
+        pub title: String,
+        /// Font of the title.
+        pub font: IntoAsset<Font>,
+        /// Color of the title, default is white.
+        pub text_color: Option<Color>,
+        /// Color of the title bar, default is dark gray.
+        pub bar_color: Option<Color>,
+        /// Height of the title bar, default is `2em`.
+        pub bar_height: Option<Size>,
+        /// If set, adds a close button that sends a signal when clicked.
+        pub on_close: Option<TypedSignal<Object>>,
+    }
+);
+
+impl Widget for WindowBuilder {
+    fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
+        let font = commands.load_or_default(std::mem::take(&mut self.font));
+        let color = self.color.unwrap_or(Color::GRAY);
+        let text_color = self.text_color.unwrap_or(Color::WHITE);
+        let bar_color = self.bar_color.unwrap_or(Color::DARK_GRAY);
+        let bar_height = self.bar_height.unwrap_or(Size::new(SizeUnit::Em, 2.0));
+        let title = std::mem::take(&mut self.title);
+        let on_close = self.on_close.take();
+        let (drag_send, drag_recv) = signal::<DragState, _>();
+        let mut entity = build_frame!(commands, self);
+        entity.insert((
+            Sprite {
+                color,
+                ..Default::default()
+            },
+            Handle::<Image>::default(),
+            Coloring::new(color),
+            BuildTransformBundle::default(),
+            Dragging::BOTH,
+            RaiseOnDrag::default(),
+        ));
+        entity.compose(Signals::from_receiver::<Dragging>(drag_recv));
+        let entity = entity.id();
+        let bar_dimension = DimensionType::Owned(Size2::new(Size::new(SizeUnit::Percent, 1.0), bar_height));
+        let bar = rectangle!(commands {
+            anchor: Anchor::TOP_CENTER,
+            dimension: bar_dimension,
+            color: bar_color,
+            event: EventFlags::LeftDrag,
+            z: 0.01,
+            extra: LayoutControl::IgnoreLayout,
+        });
+        commands.entity(bar).compose(Signals::from_sender::<Dragging>(drag_send));
+        let title = text!(commands {
+            anchor: Anchor::CENTER_LEFT,
+            offset: Size2::em(0.5, 0.0),
+            text: title,
+            font: font.clone(),
+            color: text_color,
+        });
+        commands.entity(bar).add_child(title);
+        if let Some(on_close) = on_close {
+            let (close, _) = ButtonBuilder {
+                anchor: Anchor::CENTER_RIGHT,
+                dimension: DimensionType::Owned(Size2::new(bar_height, bar_height)),
+                on_click: Some(on_close),
+                ..Default::default()
+            }.spawn(commands);
+            let label = text!(commands {
+                text: "x",
+                font: font,
+                color: text_color,
+            });
+            commands.entity(close).add_child(label);
+            commands.entity(bar).add_child(close);
+        }
+        commands.entity(entity).add_child(bar);
+        (entity, entity)
+    }
+}
+
+/// Construct a draggable window with a title bar. The underlying struct is [`WindowBuilder`].
+///
+/// Dragging the title bar moves the window, which is constrained within its parent
+/// or the screen and brought to the front of its siblings.
+///
+/// The title bar covers the top `bar_height` of the window and is ignored by `layout`,
+/// leave room for it with `padding` or an offset.
+#[macro_export]
+macro_rules! window {
+    {$commands: tt {$($tt:tt)*}} =>
+        {$crate::meta_dsl!($commands [$crate::dsl::builders::WindowBuilder] {$($tt)*})};
+}
+
+frame_extension!(
+    pub struct ResizeHandleBuilder {
+        /// Entity to resize, default is the parent.
+        pub target: Option<Entity>,
+        /// Minimum size of the target in pixels.
+        pub min: Option<Vec2>,
+        /// Maximum size of the target in pixels.
+        pub max: Option<Vec2>,
+        /// Sets the icon during hover and drag.
+        pub cursor: Option<CursorIcon>,
+    }
+);
+
+impl Widget for ResizeHandleBuilder {
+    fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
+        if self.anchor == Anchor::CENTER {
+            self.anchor = Anchor::BOTTOM_RIGHT;
+        }
+        let mut handle = ResizeHandle::new(self.anchor)
+            .with_bounds(self.min.unwrap_or(Vec2::ZERO), self.max.unwrap_or(Vec2::INFINITY));
+        handle.target = self.target;
+        if matches!(self.dimension, DimensionType::Copied) {
+            self.dimension = DimensionType::Owned(match (handle.direction.x, handle.direction.y) {
+                (_, 0.0) => Size2::new(Size::new(SizeUnit::Em, 0.5), Size::new(SizeUnit::Percent, 1.0)),
+                (0.0, _) => Size2::new(Size::new(SizeUnit::Percent, 1.0), Size::new(SizeUnit::Em, 0.5)),
+                _ => Size2::em(1.0, 1.0),
+            });
+        }
+        let icon = self.cursor.unwrap_or(match (handle.direction.x, handle.direction.y) {
+            (_, 0.0) => CursorIcon::EwResize,
+            (0.0, _) => CursorIcon::NsResize,
+            (x, y) if x == y => CursorIcon::NeswResize,
+            _ => CursorIcon::NwseResize,
+        });
+        self.event |= EventFlags::Hover|EventFlags::LeftDrag;
+        self.z = if self.z == 0.0 {0.01} else {self.z};
+        let color = self.color;
+        let mut entity = build_frame!(commands, self);
+        entity.insert((
+            handle,
+            LayoutControl::IgnoreLayout,
+            SetCursor {
+                flags: EventFlags::Hover|EventFlags::LeftDrag,
+                icon,
+            },
+        ));
+        if let Some(color) = color {
+            entity.insert((
+                Sprite {
+                    color,
+                    ..Default::default()
+                },
+                Handle::<Image>::default(),
+                Coloring::new(color),
+                BuildTransformBundle::default(),
+            ));
+        }
+        let entity = entity.id();
+        (entity, entity)
+    }
+}
+
+/// Construct a handle that resizes its parent when dragged. The underlying struct is [`ResizeHandleBuilder`].
+///
+/// `anchor` determines the edge or corner the handle is placed on and resizes,
+/// default is `BOTTOM_RIGHT`. The handle is ignored by `layout` and follows
+/// the target as it resizes. The target must have an owned dimension in pixels.
+///
+/// If `color` is set, the handle is rendered as a rectangle.
+#[macro_export]
+macro_rules! resize_handle {
+    {$commands: tt {$($tt:tt)*}} =>
+        {$crate::meta_dsl!($commands [$crate::dsl::builders::ResizeHandleBuilder] {$($tt)*})};
+}
+
+frame_extension!(
+    pub struct TableBuilder {
+        /// Widths of the columns, percentages are relative to the table's width.
+        pub columns: Vec<(SizeUnit, f32)>,
+        /// Labels of the header, one for each column.
+        pub headers: Vec<String>,
+        /// Data of the rows, each row is split into cells by column.
+        pub rows: Vec<Vec<String>>,
+        /// If set, builds each cell from its data instead of spawning a `text`.
+        pub cell: Option<WidgetBuilder<String>>,
+        /// Font of the header and cells.
+        pub font: IntoAsset<Font>,
+        /// Color of the header and cells, default is white.
+        pub text_color: Option<Color>,
+        /// Background color of the header, default is dark gray.
+        pub header_color: Option<Color>,
+        /// Height of the header, default is `2em`.
+        pub header_height: Option<Size>,
+        /// Sends the new [`TableSort`] when a header is clicked.
+        pub on_sort: Option<TypedSignal<TableSort>>,
+    }
+);
+
+/// `100%` of the parent minus `size`.
+fn full_minus(size: Size) -> Size {
+    let value = size.value;
+    match size.unit {
+        SizeUnit::Pixels => Size::new(SizeUnit::MarginPx, -value),
+        SizeUnit::Em => Size::new(SizeUnit::MarginEm, -value),
+        SizeUnit::Rem => Size::new(SizeUnit::MarginRem, -value),
+        SizeUnit::Percent => Size::new(SizeUnit::Percent, 1.0 - value),
+        SizeUnit::MarginPx => Size::new(SizeUnit::Pixels, -value),
+        SizeUnit::MarginEm => Size::new(SizeUnit::Em, -value),
+        SizeUnit::MarginRem => Size::new(SizeUnit::Rem, -value),
+    }
+}
+
+impl Widget for TableBuilder {
+    fn spawn(mut self, commands: &mut RCommands) -> (Entity, Entity) {
+        let font = commands.load_or_default(std::mem::take(&mut self.font));
+        let text_color = self.text_color.unwrap_or(Color::WHITE);
+        let header_color = self.header_color.unwrap_or(Color::DARK_GRAY);
+        let header_height = self.header_height.unwrap_or(Size::new(SizeUnit::Em, 2.0));
+        let columns = std::mem::take(&mut self.columns);
+        let headers = std::mem::take(&mut self.headers);
+        let rows = std::mem::take(&mut self.rows);
+        let cell = self.cell.take();
+        let on_sort = self.on_sort.take();
+        let (click_send, click_recv) = signal::<Object, _>();
+        let mut entity = build_frame!(commands, self);
+        entity.insert(TableSort::default());
+        entity.compose(Signals::from_receiver::<TableHeaderClick>(click_recv));
+        if let Some(on_sort) = on_sort {
+            entity.compose(Signals::from_sender::<TableSort>(on_sort));
+        }
+        let entity = entity.id();
+        let header = rectangle!(commands {
+            anchor: Anchor::TOP_CENTER,
+            dimension: DimensionType::Owned(Size2::new(Size::new(SizeUnit::Percent, 1.0), header_height)),
+            layout: TableLayout::from_columns(columns.clone()),
+            color: header_color,
+            z: 0.01,
+            extra: LayoutControl::IgnoreLayout,
+        });
+        for (index, (label, (unit, width))) in headers.into_iter().zip(columns.iter().copied()).enumerate() {
+            let (button, _) = ButtonBuilder {
+                dimension: DimensionType::Owned(Size2::new(Size::new(unit, width), header_height)),
+                payload: Some(Payload::new(index)),
+                on_click: Some(click_send.clone()),
+                ..Default::default()
+            }.spawn(commands);
+            let label = text!(commands {
+                anchor: Anchor::CENTER_LEFT,
+                offset: Size2::em(0.5, 0.0),
+                text: label,
+                font: font.clone(),
+                color: text_color,
+            });
+            commands.entity(button).add_child(label);
+            commands.entity(header).add_child(button);
+        }
+        let body = frame!(commands {
+            anchor: Anchor::TOP_CENTER,
+            offset: Size2::new(Size::new(SizeUnit::Pixels, 0.0), Size::new(header_height.unit, -header_height.value)),
+            dimension: Size2::new(Size::new(SizeUnit::Percent, 1.0), full_minus(header_height)),
+            layout: TableLayout::from_columns(columns),
+        });
+        for row in rows {
+            for data in row {
+                let child = match &cell {
+                    Some(cell) => commands.spawn_fn(cell, data),
+                    None => text!(commands {
+                        anchor: Anchor::CENTER_LEFT,
+                        text: data,
+                        font: font.clone(),
+                        color: text_color,
+                    }),
+                };
+                commands.entity(body).add_child(child);
+            }
+        }
+        commands.entity(entity).push_children(&[header, body]);
+        (entity, body)
+    }
+}
+
+/// Construct a table with a clickable header row. The underlying struct is [`TableBuilder`].
+///
+/// Cells are laid out with a [`TableLayout`] sharing `columns` with the header,
+/// children are appended to the body as additional cells.
+///
+/// Clicking a header toggles the [`TableSort`] of the table and sends it through `on_sort`,
+/// rows are not reordered by the table itself.
+///
+/// The header is a sibling of the body and stays in place,
+/// so scrolling only the body keeps the header visible.
+/// The body fills the rest of the table below the header.
+#[macro_export]
+macro_rules! table {
+    {$commands: tt {$($tt:tt)*}} =>
+        {$crate::meta_dsl!($commands [$crate::dsl::builders::TableBuilder] {$($tt)*})};
+}
@@ -0,0 +1,141 @@
+use bevy::ecs::{component::Component, entity::Entity, query::Has, world::EntityWorldMut};
+use bevy::ecs::system::{Commands, Query, Res, Resource};
+use bevy::input::{keyboard::KeyCode, ButtonInput};
+
+use super::{CursorAction, CursorState, EventFlags, InputBlock, DescendantHasFocus};
+
+/// Maps logical actions like `"confirm"` to rebindable keys.
+///
+/// An action can be bound to multiple keys. If a key is bound to multiple actions,
+/// the earliest binding wins, so conflicts are always resolved the same way.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct ActionMap {
+    bindings: Vec<(String, KeyCode)>,
+}
+
+impl ActionMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind a key to an action.
+    pub fn with_binding(mut self, action: impl Into<String>, key: KeyCode) -> Self {
+        self.bind(action, key);
+        self
+    }
+
+    /// Bind a key to an action, does nothing if already bound.
+    pub fn bind(&mut self, action: impl Into<String>, key: KeyCode) {
+        let action = action.into();
+        if !self.bindings.iter().any(|(a, k)| a == &action && k == &key) {
+            self.bindings.push((action, key));
+        }
+    }
+
+    /// Replace all keys bound to an action.
+    pub fn rebind(&mut self, action: impl Into<String>, keys: impl IntoIterator<Item = KeyCode>) {
+        let action = action.into();
+        self.unbind(&action);
+        for key in keys {
+            self.bind(action.clone(), key);
+        }
+    }
+
+    /// Remove all keys bound to an action.
+    pub fn unbind(&mut self, action: &str) {
+        self.bindings.retain(|(a, _)| a != action);
+    }
+
+    /// Remove a key from all actions.
+    pub fn unbind_key(&mut self, key: KeyCode) {
+        self.bindings.retain(|(_, k)| k != &key);
+    }
+
+    /// Obtain keys bound to an action.
+    pub fn keys<'t>(&'t self, action: &'t str) -> impl Iterator<Item = KeyCode> + 't {
+        self.bindings.iter()
+            .filter(move |(a, _)| a == action)
+            .map(|(_, k)| *k)
+    }
+
+    /// Obtain the action a key resolves to.
+    pub fn action(&self, key: KeyCode) -> Option<&str> {
+        self.bindings.iter()
+            .find(|(_, k)| k == &key)
+            .map(|(a, _)| a.as_str())
+    }
+
+    /// Returns true if a key resolving to this action is just pressed.
+    pub fn just_pressed(&self, action: &str, input: &ButtonInput<KeyCode>) -> bool {
+        input.get_just_pressed().any(|key| self.action(*key) == Some(action))
+    }
+
+    /// Returns true if a key resolving to this action is pressed.
+    pub fn pressed(&self, action: &str, input: &ButtonInput<KeyCode>) -> bool {
+        input.get_pressed().any(|key| self.action(*key) == Some(action))
+    }
+}
+
+/// Send a `CursorAction` to this entity when an action in [`ActionMap`] is just pressed.
+///
+/// With the default `LeftClick`, this behaves like clicking a `Button`.
+#[derive(Debug, Clone, Component, PartialEq, Eq)]
+pub struct ActionTrigger {
+    pub action: String,
+    pub flags: EventFlags,
+    /// If set, only triggers if this entity is hovered or has a focused descendant.
+    pub focused_only: bool,
+}
+
+impl ActionTrigger {
+    /// Trigger a `LeftClick` regardless of focus.
+    pub fn new(action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            flags: EventFlags::LeftClick,
+            focused_only: false,
+        }
+    }
+
+    /// Trigger a `LeftClick` only if focused.
+    pub fn focused(action: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            flags: EventFlags::LeftClick,
+            focused_only: true,
+        }
+    }
+
+    pub fn with_flags(mut self, flags: EventFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+}
+
+pub(crate) fn action_trigger(
+    mut commands: Commands,
+    map: Option<Res<ActionMap>>,
+    block: Res<InputBlock>,
+    input: Res<ButtonInput<KeyCode>>,
+    state: Res<CursorState>,
+    query: Query<(Entity, &ActionTrigger, Has<DescendantHasFocus>)>,
+) {
+    let Some(map) = map else {return};
+    if block.is_blocked() || input.get_just_pressed().next().is_none() {
+        return;
+    }
+    for (entity, trigger, has_focus) in query.iter() {
+        if trigger.focused_only && !has_focus && state.focused != Some(entity) {
+            continue;
+        }
+        if !map.just_pressed(&trigger.action, &input) {
+            continue;
+        }
+        let flags = trigger.flags;
+        commands.entity(entity).add(move |mut entity: EntityWorldMut| {
+            if !entity.contains::<CursorAction>() {
+                entity.insert(CursorAction(flags));
+            }
+        });
+    }
+}
@@ -27,6 +27,10 @@ impl CursorFocus {
 pub struct CursorAction(pub(super) EventFlags);
 
 impl CursorAction {
+    /// Synthesize a cursor event, i.e. from keyboard input.
+    pub const fn new(flags: EventFlags) -> Self {
+        Self(flags)
+    }
     pub fn flags(&self) -> EventFlags {
         self.0
     }
@@ -58,6 +62,7 @@ pub(super) mod sealed {
         /// Valid listeners are `Hover`, `*Click`, `*Drag`, `DoubleClick`, `Drop` and `ClickOutside`.
         ///
         /// * `Hover` listens for `Hover`,
+        /// * `HoverEnter` and `HoverExit` listen for the first and last frame of `Hover` or `Pressed`.
         /// * `Click` listens for `Down`, `Up` and `Pressed`
         /// * `Drag` listens for `Down`, `DragEnd` and `Drag`
         /// * `DoubleClick` listens for `DoubleClick`, which replaces `Click` or `DragEnd`
@@ -66,7 +71,7 @@ pub(super) mod sealed {
         ///
         /// Events are emitted as 3 separate components, each frame a sprite can receive at most one of each:
         /// * `CursorFocus`: `Hover`, `Pressed`, `Drag`.
-        /// * `CursorAction`: `Down`, `Click`, `DragEnd`, `DoubleClick`, `Drop`.
+        /// * `CursorAction`: `Down`, `Click`, `DragEnd`, `DoubleClick`, `Drop`, `HoverEnter`, `HoverExit`.
         /// * `CursorClickOutside`: `ClickOutside`.
         ///
         /// Details:
@@ -95,6 +100,8 @@ pub(super) mod sealed {
             pub DragEnd,
             pub ClickOutside,
             pub MouseWheel,
+            pub HoverEnter,
+            pub HoverExit,
         }
     );
 
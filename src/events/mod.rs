@@ -31,16 +31,25 @@
 //! * `CursorClickOutside`: Mouse up outside of the sprite's boundary.
 //! * `MouseWheelAction`: Stores the value of mouse wheel scrolling.
 //!
+//! # Gameplay Input
+//!
+//! Read the [`PointerOverUi`] resource or [`CursorState::caught`] to ignore
+//! clicks that hit the UI, both are updated in `PreUpdate`.
+//!
 //! # What about Keyboard Events or Joysticks?
 //!
 //! We provide abstractions that you can use for other types of input,
 //! but these are outside the scope of this crate.
+//!
+//! Insert an [`ActionMap`] resource to bind keys to logical actions,
+//! then add [`ActionTrigger`] to widgets to send them a `CursorAction` when
+//! an action is pressed.
 
 use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
 use crate::{Hitbox, Clipping, RotatedRect, Opacity};
 use crate::widgets::util::{CursorDefault, remove_all};
-use crate::schedule::{CleanupSet, EventSet, WidgetEventSet};
+use crate::schedule::{CleanupSet, EventSet, PostEventSet, WidgetEventSet};
 
 pub(crate) mod systems;
 pub(crate) mod wheel;
@@ -49,15 +58,18 @@ mod event;
 mod cursor;
 mod gbb;
 mod focus;
+mod action;
 
 pub use event::*;
 pub use state::*;
 use systems::*;
-pub use wheel::{MovementUnits, ScrollScaling, MouseWheelAction};
+pub(crate) use systems::mouse_button_input;
+pub use wheel::{MovementUnits, ScrollScaling, ScrollSpeed, MouseWheelAction};
 pub use cursor::{CustomCursor, TrackCursor};
 pub use cursor::CameraQuery;
 pub use gbb::{GreaterBoundingBox, GreaterBoundingBoxPercent, GreaterBoundingBoxPx};
 pub use focus::*;
+pub use action::{ActionMap, ActionTrigger};
 
 use self::gbb::calculate_greater_bounding_box;
 use self::cursor::{custom_cursor_controller, track_cursor};
@@ -115,12 +127,19 @@ pub(crate) struct CursorEventsPlugin;
 impl bevy::prelude::Plugin for CursorEventsPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app.init_resource::<CursorState>()
+            .init_resource::<InputBlock>()
             .init_resource::<ScrollScaling>()
+            .init_resource::<ScrollSpeed>()
             .init_resource::<DoubleClickThreshold>()
+            .init_resource::<DragThreshold>()
             .init_resource::<CursorDefault>()
+            .init_resource::<PointerOverUi>()
             .add_systems(PreUpdate, mouse_button_input.in_set(EventSet))
             .add_systems(PreUpdate, mouse_button_click_outside.in_set(EventSet).after(mouse_button_input))
+            .add_systems(PreUpdate, hover_enter_exit.in_set(EventSet).after(mouse_button_input))
             .add_systems(PreUpdate, wheel::mousewheel_event.in_set(EventSet))
+            .add_systems(PreUpdate, action::action_trigger.in_set(EventSet).after(mouse_button_input))
+            .add_systems(PreUpdate, state::update_pointer_over_ui.after(EventSet).before(PostEventSet))
             .add_systems(PreUpdate, focus::run_focus_signals.in_set(WidgetEventSet))
             .add_systems(PreUpdate, focus::run_strong_focus_signals.in_set(WidgetEventSet))
             .add_systems(FixedUpdate, (
@@ -1,5 +1,5 @@
 use bevy::{ecs::entity::Entity, input::mouse::MouseButton, math::Vec2, reflect::Reflect};
-use bevy::ecs::system::{Resource, Commands, EntityCommands};
+use bevy::ecs::{component::Component, system::{Resource, Commands, EntityCommands, Res, ResMut}};
 
 /// Time threshold in seconds for double click.
 #[derive(Debug, Resource, Reflect)]
@@ -21,18 +21,61 @@ impl DoubleClickThreshold {
     }
 }
 
+/// Resource that blocks all cursor, wheel and keyboard input to widgets while `true`.
+///
+/// Useful for cutscenes or loading screens, can be toggled from async tasks.
+/// Input received while blocked is discarded instead of fired when unblocked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Resource, Reflect)]
+pub struct InputBlock(pub bool);
+
+impl InputBlock {
+    pub fn is_blocked(&self) -> bool {
+        self.0
+    }
+}
+
+/// Distance in logical pixels the cursor must move after pressing before a drag starts.
+///
+/// Releasing before reaching the threshold resolves as a click.
+/// As a resource this is the global default, as a component this overrides the default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Resource, Component, Reflect)]
+pub struct DragThreshold(pub f32);
+
+/// Is `true` if the cursor is over, pressing or dragging a widget this frame,
+/// use this to ignore gameplay input that hits the UI.
+///
+/// Updated in `PreUpdate` after [`EventSet`](crate::schedule::EventSet),
+/// systems in `Update` see the value for the current frame.
+/// Always `false` while input is blocked by [`CursorState::set_blocked`] or [`InputBlock`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Resource, Reflect)]
+pub struct PointerOverUi(pub bool);
+
+pub(crate) fn update_pointer_over_ui(
+    state: Res<CursorState>,
+    block: Res<InputBlock>,
+    mut res: ResMut<PointerOverUi>,
+) {
+    let value = state.caught && !state.blocked && !block.is_blocked();
+    if res.0 != value {
+        res.0 = value;
+    }
+}
+
 /// State of the cursor system.
 #[derive(Debug, Resource, Reflect)]
 pub struct CursorState{
     pub(super) last_lmb_down_time: [f32; 2],
     pub(super) cursor_pos: Vec2,
+    pub(super) in_window: bool,
     pub(super) up_pos: Vec2,
     pub(super) down_pos: Vec2,
+    pub(super) down_screen_pos: Vec2,
     pub(super) blocked: bool,
     pub(super) caught: bool,
     pub(super) dragging: bool,
     pub(super) drag_button: MouseButton,
     pub(super) drag_target: Option<Entity>,
+    pub(super) drag_pending: bool,
     pub(super) focused: Option<Entity>,
     pub(super) drag_dbl_click: bool,
 }
@@ -42,12 +85,15 @@ impl Default for CursorState {
         Self {
             last_lmb_down_time: [0.0, 0.0],
             cursor_pos: Vec2::ZERO,
+            in_window: false,
             up_pos: Vec2::ZERO,
             down_pos: Vec2::ZERO,
+            down_screen_pos: Vec2::ZERO,
             blocked: false,
             dragging: false,
             drag_button: MouseButton::Left,
             drag_target: None,
+            drag_pending: false,
             focused: None,
             caught: false,
             drag_dbl_click: false,
@@ -62,6 +108,15 @@ impl CursorState {
         self.caught
     }
 
+    /// Returns true if the cursor is over, pressing or dragging a widget this frame,
+    /// alias of [`is_handled_this_frame`](Self::is_handled_this_frame).
+    ///
+    /// Updated in `PreUpdate` in [`EventSet`](crate::schedule::EventSet),
+    /// use [`PointerOverUi`] to also account for blocked input.
+    pub fn caught(&self) -> bool {
+        self.is_handled_this_frame()
+    }
+
     /// Call if some external system caught mouse events this frame before this.
     ///
     /// Does not cancel dragging.
@@ -79,12 +134,30 @@ impl CursorState {
         self.last_lmb_down_time = [0.0, 0.0];
         self.blocked = true;
         self.drag_target = None;
+        self.drag_pending = false;
         self.dragging = false;
     }
 
+    /// Block or unblock all input until changed again.
+    ///
+    /// Blocking force ends dragging.
+    pub fn set_blocked(&mut self, blocked: bool) {
+        if blocked {
+            self.block_force();
+        } else {
+            self.blocked = false;
+        }
+    }
+
+    /// Returns true if input is blocked.
+    pub fn is_blocked(&self) -> bool {
+        self.blocked
+    }
+
     /// Cancels dragging of the current entity, does not reset mouse state.
     pub fn cancel_drag(&mut self) {
         self.drag_target = None;
+        self.drag_pending = false;
     }
 
     /// Cancels dragging of the current entity, does not reset mouse state.
@@ -97,6 +170,7 @@ impl CursorState {
         commands.get_entity(self.drag_target?)
     }
 
+    /// World position of the cursor when a mouse button was last pressed.
     pub fn down_position(&self) -> Vec2 {
         self.down_pos
     }
@@ -105,10 +179,29 @@ impl CursorState {
         self.up_pos
     }
 
+    /// World position of the cursor, same as [`CursorState::world_position`].
     pub fn cursor_position(&self) -> Vec2 {
         self.cursor_pos
     }
 
+    /// World position of the cursor computed through the rectray camera,
+    /// updated in `PreUpdate`.
+    ///
+    /// Returns the last known value if the cursor has left the window.
+    pub fn world_position(&self) -> Vec2 {
+        self.cursor_pos
+    }
+
+    /// Entity hovered, pressed or dragged by the cursor this frame.
+    pub fn focused(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    /// Returns true if the cursor was inside the window this frame.
+    pub fn in_window(&self) -> bool {
+        self.in_window
+    }
+
     pub fn dragging(&self) -> bool {
         self.dragging
     }
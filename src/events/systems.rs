@@ -19,11 +19,15 @@ pub fn mouse_button_input(
     mut state: ResMut<CursorState>,
     time: Res<Time>,
     double_click: Res<DoubleClickThreshold>,
+    drag_threshold: Res<DragThreshold>,
+    block: Res<InputBlock>,
     buttons: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window, With<PrimaryWindow>>,
     camera: CameraQuery,
     query: Query<(Entity, &EventFlags, CursorDetection, ActiveDetection)>,
+    thresholds: Query<&DragThreshold>,
 ) {
+    let threshold = |entity: Entity| thresholds.get(entity).unwrap_or(&*drag_threshold).0;
     let iter = |f: EventFlags|query.iter().filter_map(move |(entity, flag, cursor, detection)| {
         if detection.is_active() && flag.intersects(f) {
             Some((entity, flag, cursor))
@@ -33,12 +37,21 @@ pub fn mouse_button_input(
     });
     state.caught = false;
     state.focused = None;
-    if state.blocked { return; }
+    if state.blocked || block.is_blocked() { return; }
     let Ok(window) = windows.get_single() else { return };
-    let Some(mouse_pos) = window.cursor_position()
-        .and_then(|cursor| camera.viewport_to_world(cursor))
-    else {return;};
+    state.in_window = false;
+    let Some(screen_pos) = window.cursor_position() else {return;};
+    let Some(mouse_pos) = camera.viewport_to_world(screen_pos) else {return;};
+    state.in_window = true;
     state.cursor_pos = mouse_pos;
+    if buttons.any_just_pressed([MouseButton::Left, MouseButton::Right, MouseButton::Middle]) {
+        state.down_screen_pos = screen_pos;
+    }
+    if state.drag_pending && !buttons.pressed(state.drag_button) {
+        // Released before reaching the threshold, resolve as a click.
+        state.drag_pending = false;
+        state.drag_target = None;
+    }
     if state.dragging {
         state.caught = true;
         if let Some(mut entity) = state.drag_target(&mut commands) {
@@ -82,6 +95,30 @@ pub fn mouse_button_input(
             state.dragging = false;
             state.drag_target = None;
         }
+    } else if state.drag_pending {
+        if let Some(entity) = state.drag_target {
+            state.caught = true;
+            state.focused = Some(entity);
+            if screen_pos.distance(state.down_screen_pos) > threshold(entity) {
+                state.drag_pending = false;
+                state.dragging = true;
+                commands.entity(entity).insert(CursorFocus(match state.drag_button {
+                    MouseButton::Left => EventFlags::LeftDrag,
+                    MouseButton::Right => EventFlags::RightDrag,
+                    MouseButton::Middle => EventFlags::MidDrag,
+                    _ => EventFlags::LeftDrag,
+                }));
+            } else {
+                commands.entity(entity).insert(CursorFocus(match state.drag_button {
+                    MouseButton::Left => EventFlags::LeftPressed,
+                    MouseButton::Right => EventFlags::RightPressed,
+                    MouseButton::Middle => EventFlags::MidPressed,
+                    _ => EventFlags::LeftPressed,
+                }));
+            }
+        } else {
+            state.drag_pending = false;
+        }
     } else if buttons.pressed(MouseButton::Left) {
         if buttons.just_pressed(MouseButton::Left) {
             state.down_pos = mouse_pos;
@@ -98,10 +135,15 @@ pub fn mouse_button_input(
                 commands.entity(entity).insert(CursorAction(EventFlags::LeftDown));
                 if flag.contains(EventFlags::LeftDrag) {
                     state.drag_target = Some(entity);
-                    state.dragging = true;
                     state.drag_button = MouseButton::Left;
                     state.drag_dbl_click = flag.contains(EventFlags::DoubleClick);
-                    commands.entity(entity).insert(CursorFocus(EventFlags::LeftDrag));
+                    if threshold(entity) > 0.0 {
+                        state.drag_pending = true;
+                        commands.entity(entity).insert(CursorFocus(EventFlags::LeftPressed));
+                    } else {
+                        state.dragging = true;
+                        commands.entity(entity).insert(CursorFocus(EventFlags::LeftDrag));
+                    }
                     state.focused = Some(entity);
                 } else {
                     commands.entity(entity).insert(CursorFocus(EventFlags::LeftPressed));
@@ -128,7 +170,13 @@ pub fn mouse_button_input(
                     state.drag_target = Some(entity);
                     state.drag_button = MouseButton::Right;
                     state.drag_dbl_click = false;
-                    commands.entity(entity).insert(CursorFocus(EventFlags::RightDrag));
+                    if threshold(entity) > 0.0 {
+                        state.drag_pending = true;
+                        commands.entity(entity).insert(CursorFocus(EventFlags::RightPressed));
+                    } else {
+                        state.dragging = true;
+                        commands.entity(entity).insert(CursorFocus(EventFlags::RightDrag));
+                    }
                     state.focused = Some(entity);
                 } else {
                     commands.entity(entity).insert(CursorFocus(EventFlags::RightPressed));
@@ -156,7 +204,13 @@ pub fn mouse_button_input(
                     state.drag_target = Some(entity);
                     state.drag_button = MouseButton::Middle;
                     state.drag_dbl_click = false;
-                    commands.entity(entity).insert(CursorFocus(EventFlags::MidDrag));
+                    if threshold(entity) > 0.0 {
+                        state.drag_pending = true;
+                        commands.entity(entity).insert(CursorFocus(EventFlags::MidPressed));
+                    } else {
+                        state.dragging = true;
+                        commands.entity(entity).insert(CursorFocus(EventFlags::MidDrag));
+                    }
                     state.focused = Some(entity);
                 } else {
                     commands.entity(entity).insert(CursorFocus(EventFlags::MidPressed));
@@ -178,7 +232,7 @@ pub fn mouse_button_input(
                         commands.entity(entity).insert(CursorAction(EventFlags::DoubleClick));
                         state.clear_dbl_click();
                     } else {
-                        commands.entity(entity).insert(CursorAction(EventFlags::LeftClick));
+                        commands.entity(entity).insert(CursorAction::new(EventFlags::LeftClick));
                     }
                 )
                 .exec(|| state.caught = true);
@@ -198,7 +252,7 @@ pub fn mouse_button_input(
                 .exec(|| state.caught = true);
         }
         if state.focused.is_none() {
-            iter(EventFlags::Hover)
+            iter(EventFlags::Hover|EventFlags::HoverEnter|EventFlags::HoverExit)
                 .filter(|(.., hitbox)| hitbox.contains(mouse_pos))
                 .max_by(|(.., a), (.., b)| a.compare(b))
                 .map(|(entity, ..)| {
@@ -210,6 +264,41 @@ pub fn mouse_button_input(
     }
 }
 
+/// Emit edge triggered `HoverEnter` and `HoverExit` by diffing the focused entity.
+///
+/// Does not overwrite other `CursorAction`s emitted this frame.
+pub fn hover_enter_exit(
+    mut commands: Commands,
+    state: Res<CursorState>,
+    query: Query<&EventFlags>,
+    mut prev: Local<Option<Entity>>,
+) {
+    fn send(commands: &mut Commands, entity: Entity, flag: EventFlags) {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.add(move |mut entity: EntityWorldMut| {
+                if !entity.contains::<CursorAction>() {
+                    entity.insert(CursorAction(flag));
+                }
+            });
+        }
+    }
+    let current = state.focused;
+    if current == *prev {
+        return;
+    }
+    if let Some(entity) = *prev {
+        if query.get(entity).is_ok_and(|x| x.contains(EventFlags::HoverExit)) {
+            send(&mut commands, entity, EventFlags::HoverExit);
+        }
+    }
+    if let Some(entity) = current {
+        if query.get(entity).is_ok_and(|x| x.contains(EventFlags::HoverEnter)) {
+            send(&mut commands, entity, EventFlags::HoverEnter);
+        }
+    }
+    *prev = current;
+}
+
 pub fn mouse_button_click_outside(
     mut commands: Commands,
     state: Res<CursorState>,
@@ -236,4 +325,57 @@ pub fn mouse_button_click_outside(
         .filter(|(_, flags)| flags.contains(EventFlags::ClickOutside))
         .filter(|(entity, _)| !focused.contains(entity))
         .for_each(|(entity, _)| commands.entity(entity).insert(CursorClickOutside).end())
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod test {
+    use bevy::ecs::system::{IntoSystem, System};
+    use bevy::prelude::{Entity, World};
+    use super::{hover_enter_exit, CursorAction, CursorState, EventFlags};
+
+    fn frame(world: &mut World, system: &mut impl System<In = (), Out = ()>, focused: Option<Entity>) {
+        let entities: Vec<_> = world.iter_entities().map(|x| x.id()).collect();
+        for entity in entities {
+            world.entity_mut(entity).remove::<CursorAction>();
+        }
+        world.resource_mut::<CursorState>().focused = focused;
+        system.run((), world);
+        system.apply_deferred(world);
+    }
+
+    fn action(world: &World, entity: Entity) -> Option<EventFlags> {
+        world.get::<CursorAction>(entity).map(|x| x.flags())
+    }
+
+    #[test]
+    fn hover_toggle_across_frames() {
+        let mut world = World::new();
+        world.init_resource::<CursorState>();
+        let mut system = IntoSystem::into_system(hover_enter_exit);
+        system.initialize(&mut world);
+        let a = world.spawn(EventFlags::HoverEnter | EventFlags::HoverExit).id();
+        let b = world.spawn(EventFlags::HoverEnter | EventFlags::HoverExit).id();
+
+        frame(&mut world, &mut system, Some(a));
+        assert_eq!(action(&world, a), Some(EventFlags::HoverEnter));
+        assert_eq!(action(&world, b), None);
+
+        // Level hover does not retrigger.
+        frame(&mut world, &mut system, Some(a));
+        assert_eq!(action(&world, a), None);
+
+        frame(&mut world, &mut system, Some(b));
+        assert_eq!(action(&world, a), Some(EventFlags::HoverExit));
+        assert_eq!(action(&world, b), Some(EventFlags::HoverEnter));
+
+        frame(&mut world, &mut system, None);
+        assert_eq!(action(&world, a), None);
+        assert_eq!(action(&world, b), Some(EventFlags::HoverExit));
+
+        // Hover back in, does not overwrite an existing action.
+        world.entity_mut(a).insert(CursorAction::new(EventFlags::LeftClick));
+        world.resource_mut::<CursorState>().focused = Some(a);
+        system.run((), &mut world);
+        system.apply_deferred(&mut world);
+        assert_eq!(action(&world, a), Some(EventFlags::LeftClick));
+    }
+}
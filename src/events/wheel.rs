@@ -4,7 +4,7 @@ use bevy_defer::signals::SignalId;
 
 use crate::widgets::clipping::CameraClip;
 
-use super::{EventFlags, RectrayCamera, CursorDetection, ActiveDetection};
+use super::{EventFlags, RectrayCamera, CursorDetection, ActiveDetection, CursorState, InputBlock};
 
 
 
@@ -25,6 +25,67 @@ impl Default for ScrollScaling {
     }
 }
 
+/// Resource that scales mouse wheel scrolling speed by delta mode.
+///
+/// Line based devices (mouse wheels) and pixel based devices (trackpads)
+/// report deltas in different magnitudes, this normalizes them.
+#[derive(Debug, Clone, Copy, Resource, Reflect)]
+pub struct ScrollSpeed {
+    /// Multiplier for line based deltas.
+    pub line: f32,
+    /// Multiplier for pixel based deltas.
+    pub pixel: f32,
+    /// If set, pixel based deltas are converted from physical to logical pixels
+    /// using the window's scale factor.
+    pub dpi_aware: bool,
+}
+
+impl Default for ScrollSpeed {
+    fn default() -> Self {
+        Self {
+            line: 1.0,
+            pixel: 1.0,
+            dpi_aware: true,
+        }
+    }
+}
+
+impl ScrollSpeed {
+    /// Convert a line based delta to pixels.
+    pub fn scale_lines(&self, scaling: &ScrollScaling, lines: Vec2) -> Vec2 {
+        lines * scaling.line_to_pixels * scaling.pixel_scale * self.line
+    }
+
+    /// Convert a pixel based delta to logical pixels.
+    pub fn scale_pixels(&self, scaling: &ScrollScaling, pixels: Vec2, scale_factor: f32) -> Vec2 {
+        let pixels = if self.dpi_aware && scale_factor > 0.0 {
+            pixels / scale_factor
+        } else {
+            pixels
+        };
+        pixels * scaling.pixel_scale * self.pixel
+    }
+
+    /// Add a wheel event to `units`,
+    /// `remainder` holds pixels of pixel based deltas not yet counted as lines.
+    pub(crate) fn accumulate(&self, scaling: &ScrollScaling, units: &mut MovementUnits,
+            remainder: &mut Vec2, unit: MouseScrollUnit, delta: Vec2, scale_factor: f32) {
+        match unit {
+            MouseScrollUnit::Line => {
+                units.lines += delta.as_ivec2();
+                units.pixels += self.scale_lines(scaling, delta);
+            },
+            MouseScrollUnit::Pixel => {
+                let pixels = self.scale_pixels(scaling, delta, scale_factor);
+                *remainder += pixels;
+                units.lines += (*remainder / scaling.line_to_pixels).as_ivec2();
+                *remainder %= scaling.line_to_pixels;
+                units.pixels += pixels;
+            },
+        }
+    }
+}
+
 /// Movement units associated with dragging or scrolling.
 #[derive(Debug, Clone, Copy, PartialEq, Reflect, Default)]
 pub struct MovementUnits{
@@ -58,13 +119,22 @@ impl SignalId for MouseWheelAction {
 pub(crate) fn mousewheel_event(
     mut commands: Commands,
     scaling: Res<ScrollScaling>,
+    speed: Res<ScrollSpeed>,
     windows: Query<&Window, With<PrimaryWindow>>,
     marked_camera: Query<(&Camera, &GlobalTransform), With<RectrayCamera>>,
     unmarked_camera: Query<(&Camera, &GlobalTransform), (Without<RectrayCamera>, Without<CameraClip>)>,
     query: Query<(Entity, &EventFlags, ActiveDetection, CursorDetection)>,
     mut lines: Local<Vec2>,
     mut reader: EventReader<MouseWheel>,
+    state: Res<CursorState>,
+    block: Res<InputBlock>,
 ) {
+    if state.is_blocked() || block.is_blocked() {
+        // Discard so events are not fired after unblocking.
+        reader.clear();
+        *lines = Vec2::ZERO;
+        return;
+    }
     let(camera, camera_transform) = match marked_camera.get_single() {
         Ok((cam, transform)) => (cam, transform),
         Err(_) => match unmarked_camera.get_single(){
@@ -82,31 +152,97 @@ pub(crate) fn mousewheel_event(
         .map(|(entity,..)| entity) {
 
         let mut count = 0;
+        let mut units = MovementUnits::ZERO;
+        let scale_factor = window.scale_factor();
+        // Accumulate all events this frame so high frequency devices are not truncated.
         for event in reader.read() {
             count += 1;
-            match event.unit {
-                MouseScrollUnit::Line => {
-                    let lines = Vec2::new(event.x, event.y);
-                    let pixels = lines * scaling.line_to_pixels * scaling.pixel_scale;
-                    commands.entity(entity).insert(MouseWheelAction(MovementUnits{
-                        lines: lines.as_ivec2(),
-                        pixels,
-                    }));
-                },
-                MouseScrollUnit::Pixel => {
-                    let pixels = Vec2::new(event.x, event.y) * scaling.pixel_scale;
-                    *lines += pixels;
-                    let count = (*lines / scaling.line_to_pixels).as_ivec2();
-                    *lines %= scaling.line_to_pixels;
-                    commands.entity(entity).insert(MouseWheelAction(MovementUnits{
-                        lines: count,
-                        pixels,
-                    }));
-                },
-            }
+            speed.accumulate(&scaling, &mut units, &mut lines, event.unit, Vec2::new(event.x, event.y), scale_factor);
+        }
+        if count > 0 {
+            commands.entity(entity).insert(MouseWheelAction(units));
         }
         if count == 0 {
             *lines = Vec2::ZERO;
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use bevy::input::mouse::MouseScrollUnit;
+    use bevy::math::{IVec2, Vec2};
+    use super::{MovementUnits, ScrollScaling, ScrollSpeed};
+
+    fn accumulate(speed: ScrollSpeed, events: &[(MouseScrollUnit, Vec2)], scale_factor: f32) -> (MovementUnits, Vec2) {
+        let scaling = ScrollScaling::default();
+        let mut units = MovementUnits::ZERO;
+        let mut remainder = Vec2::ZERO;
+        for (unit, delta) in events {
+            speed.accumulate(&scaling, &mut units, &mut remainder, *unit, *delta, scale_factor);
+        }
+        (units, remainder)
+    }
+
+    #[test]
+    fn line_deltas() {
+        let (units, remainder) = accumulate(ScrollSpeed::default(), &[
+            (MouseScrollUnit::Line, Vec2::new(0.0, 2.0)),
+            (MouseScrollUnit::Line, Vec2::new(1.0, 1.0)),
+        ], 2.0);
+        assert_eq!(units.lines, IVec2::new(1, 3));
+        assert_eq!(units.pixels, Vec2::new(16.0, -48.0));
+        assert_eq!(remainder, Vec2::ZERO);
+    }
+
+    #[test]
+    fn line_deltas_speed() {
+        let speed = ScrollSpeed { line: 2.0, ..Default::default() };
+        let (units, _) = accumulate(speed, &[
+            (MouseScrollUnit::Line, Vec2::new(0.0, 1.0)),
+        ], 1.0);
+        assert_eq!(units.lines, IVec2::new(0, 1));
+        assert_eq!(units.pixels, Vec2::new(0.0, -32.0));
+    }
+
+    #[test]
+    fn pixel_deltas_dpi() {
+        let (units, remainder) = accumulate(ScrollSpeed::default(), &[
+            (MouseScrollUnit::Pixel, Vec2::new(0.0, 20.0)),
+        ], 2.0);
+        assert_eq!(units.lines, IVec2::ZERO);
+        assert_eq!(units.pixels, Vec2::new(0.0, -10.0));
+        assert_eq!(remainder, Vec2::new(0.0, -10.0));
+
+        let speed = ScrollSpeed { dpi_aware: false, ..Default::default() };
+        let (units, remainder) = accumulate(speed, &[
+            (MouseScrollUnit::Pixel, Vec2::new(0.0, 20.0)),
+        ], 2.0);
+        assert_eq!(units.lines, IVec2::new(0, -1));
+        assert_eq!(units.pixels, Vec2::new(0.0, -20.0));
+        assert_eq!(remainder, Vec2::new(0.0, -4.0));
+    }
+
+    #[test]
+    fn pixel_deltas_accumulate_lines() {
+        let (units, remainder) = accumulate(ScrollSpeed::default(), &[
+            (MouseScrollUnit::Pixel, Vec2::new(12.0, 0.0)),
+            (MouseScrollUnit::Pixel, Vec2::new(12.0, 0.0)),
+            (MouseScrollUnit::Pixel, Vec2::new(12.0, 0.0)),
+        ], 1.0);
+        assert_eq!(units.lines, IVec2::new(2, 0));
+        assert_eq!(units.pixels, Vec2::new(36.0, 0.0));
+        assert_eq!(remainder, Vec2::new(4.0, 0.0));
+    }
+
+    #[test]
+    fn mixed_deltas() {
+        let (units, remainder) = accumulate(ScrollSpeed::default(), &[
+            (MouseScrollUnit::Line, Vec2::new(0.0, 1.0)),
+            (MouseScrollUnit::Pixel, Vec2::new(0.0, 8.0)),
+        ], 1.0);
+        assert_eq!(units.lines, IVec2::new(0, 1));
+        assert_eq!(units.pixels, Vec2::new(0.0, -24.0));
+        assert_eq!(remainder, Vec2::new(0.0, -8.0));
+    }
+}
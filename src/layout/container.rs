@@ -2,7 +2,7 @@ use std::ops::{Range, RangeFull, RangeInclusive};
 
 use bevy::prelude::*;
 
-use crate::{Size2, util::DslFrom};
+use crate::{Size, Size2, util::DslFrom};
 
 use super::{LayoutOutput, LayoutObject};
 
@@ -45,16 +45,16 @@ impl LayoutRange {
             LayoutRange::All => (),
             LayoutRange::Bounded { min, len } => *min = usize::min(*min, total.saturating_sub(*len)),
             LayoutRange::Capped { min, .. } => *min = usize::min(*min, total.saturating_sub(1)),
-            LayoutRange::Stepped { step, len } => *step = usize::min(*step, total / *len),
+            LayoutRange::Stepped { step, len } => *step = usize::min(*step, total.saturating_sub(1) / (*len).max(1)),
         }
     }
 
     pub fn to_range(&self, total: usize) -> Range<usize> {
         match *self {
             LayoutRange::All => 0..total,
-            LayoutRange::Bounded { min, len } => min..(min+len).min(total),
-            LayoutRange::Capped { min, len } => min..(min+len).min(total),
-            LayoutRange::Stepped { step, len } => step*len..(step*len+step).min(total),
+            LayoutRange::Bounded { min, len } => min.min(total)..(min+len).min(total),
+            LayoutRange::Capped { min, len } => min.min(total)..(min+len).min(total),
+            LayoutRange::Stepped { step, len } => (step*len).min(total)..(step*len+len).min(total),
         }
     }
 }
@@ -81,6 +81,37 @@ impl DslFrom<RangeInclusive<usize>> for LayoutRange {
     }
 }
 
+/// Padding on each side of a [`Container`] on the same entity, added to its `padding`.
+///
+/// Percentages resolve against the container's dimension.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component, Reflect)]
+pub struct PaddingSides {
+    pub top: Size,
+    pub right: Size,
+    pub bottom: Size,
+    pub left: Size,
+}
+
+impl PaddingSides {
+    pub const fn new(top: Size, right: Size, bottom: Size, left: Size) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    /// Padding in pixels as `(left, bottom)` and `(right, top)`.
+    pub fn as_pixels(&self, dimension: Vec2, em: f32, rem: f32) -> (Vec2, Vec2) {
+        (
+            Vec2::new(
+                self.left.as_pixels(dimension.x, em, rem),
+                self.bottom.as_pixels(dimension.y, em, rem),
+            ),
+            Vec2::new(
+                self.right.as_pixels(dimension.x, em, rem),
+                self.top.as_pixels(dimension.y, em, rem),
+            ),
+        )
+    }
+}
+
 /// A configurable container that lays out a sequence of Entities.
 #[derive(Debug, Component, Clone, Reflect)]
 pub struct Container {
@@ -193,11 +224,27 @@ impl Container {
 }
 
 /// Dimension info of a layout parent.
+///
+/// Construct with [`LayoutInfo::new`], more fields may be added in the future.
+#[non_exhaustive]
 pub struct LayoutInfo {
     pub dimension: Vec2,
     pub em: f32,
     pub rem: f32,
-    pub margin: Vec2
+    pub margin: Vec2,
+    pub direction: super::LayoutDirection,
+}
+
+impl LayoutInfo {
+    pub fn new(dimension: Vec2, em: f32, rem: f32, margin: Vec2) -> Self {
+        Self { dimension, em, rem, margin, direction: Default::default() }
+    }
+
+    /// Set the direction of the layout, default is left to right.
+    pub fn with_direction(mut self, direction: super::LayoutDirection) -> Self {
+        self.direction = direction;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Component, Default, Reflect, PartialEq, Eq)]
@@ -211,7 +258,9 @@ pub enum LayoutControl {
     Linebreak,
     /// Breaks the line in a container without taking up space.
     ///
-    /// Dimension is used to determine line height.
+    /// In `stack`, `span` and grid layouts, dimension is inserted as a gap
+    /// along the stacking direction or between rows.
+    /// In `paragraph` and table layouts, dimension is used to determine line height.
     ///
     /// The sprite will not be rendered and its children will not be updated.
     LinebreakMarker,
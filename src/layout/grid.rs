@@ -171,7 +171,7 @@ pub(crate) fn grid(
     let delta_cell = row_dir(cell_size);
     let delta_row = column_dir(cell_size);
     let mut row_cursor = cursor;
-    for (i, item) in items.into_iter().enumerate() {
+    for item in items {
         if item.control != LayoutControl::LinebreakMarker {
             result.push((item.entity, row_cursor + half_dir + half_size * item.anchor.as_vec()));
             row_cursor += delta_cell;
@@ -180,8 +180,12 @@ pub(crate) fn grid(
             row_ranges.push(row_start..result.len());
             max_columns = max_columns.max(result.len() - row_start);
             dimension = dimension.max((row_cursor + delta_row).abs());
-            row_start = i + 1;
+            row_start = result.len();
             cursor += delta_row;
+            if let Some(gap) = item.gap() {
+                cursor += column_dir(gap);
+                dimension = dimension.max(cursor.abs());
+            }
             row_cursor = cursor;
         }
     }
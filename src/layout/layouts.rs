@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 use std::ops::Deref;
 
 use bevy::ecs::entity::Entity;
+use bevy::utils::HashMap;
 use bevy::prelude::{Vec2, UVec2};
 use bevy::reflect::std_traits::ReflectDefault;
 use bevy::reflect::Reflect;
@@ -95,6 +96,22 @@ impl LayoutOutput {
         self.max_count = max;
         self
     }
+    /// Mirror normalized anchors horizontally if `direction` is right to left.
+    pub fn with_direction(mut self, direction: LayoutDirection, items: &[LayoutItem]) -> Self{
+        if !direction.is_rtl() || self.dimension.x == 0.0 {
+            return self;
+        }
+        // output may be reordered, look items up by entity.
+        let items: HashMap<_, _> = items.iter().map(|x| (x.entity, x)).collect();
+        for (entity, anchor) in self.entity_anchors.iter_mut() {
+            // keep the item's own anchor on the same side of the item.
+            let offset = items.get(entity)
+                .map(|x| x.anchor.x() * x.dimension.x.abs() * 2.0 / self.dimension.x)
+                .unwrap_or(0.0);
+            anchor.x = offset - anchor.x;
+        }
+        self
+    }
 }
 
 /// A dynamic dimensioned `Frame` that by default have size equal
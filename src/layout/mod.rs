@@ -5,7 +5,9 @@ pub(crate) mod layouts;
 pub(crate) mod span;
 pub(crate) mod grid;
 pub(crate) mod container;
+pub(crate) mod transition;
 
 pub use layouts::*;
 pub use util::*;
 pub use container::*;
+pub use transition::LayoutTransition;
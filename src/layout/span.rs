@@ -9,7 +9,10 @@ impl<D: Direction> Layout for StackLayout<D> {
     fn place(&self, parent: &LayoutInfo, entities: Vec<LayoutItem>, range: &mut LayoutRange) -> LayoutOutput {
         let margin = parent.margin;
         range.resolve(entities.len());
-        stack::<D>(margin, &entities[range.to_range(entities.len())]).normalized().with_max(entities.len())
+        let items = &entities[range.to_range(entities.len())];
+        stack::<D>(margin, items).normalized()
+            .with_direction(parent.direction, items)
+            .with_max(entities.len())
     }
 
     fn dyn_clone(&self) -> Box<dyn Layout> {
@@ -27,8 +30,11 @@ impl<D: StretchDir> Layout for SpanLayout<D>  {
         let dimension = parent.dimension;
         range.resolve(entities.len());
         let len = entities.len();
-        let entity_anchors = span::<D>(dimension, margin, &mut entities[range.to_range(len)]);
-        LayoutOutput { entity_anchors, dimension, max_count: entities.len() }.normalized().with_max(entities.len())
+        let items = &mut entities[range.to_range(len)];
+        let entity_anchors = span::<D>(dimension, margin, items);
+        LayoutOutput { entity_anchors, dimension, max_count: len }.normalized()
+            .with_direction(parent.direction, items)
+            .with_max(len)
     }
 
     fn dyn_clone(&self) -> Box<dyn Layout> {
@@ -40,7 +46,9 @@ impl<D1: StretchDir, D2: Direction> Layout for ParagraphLayout<D1, D2> where (D1
     fn place(&self, parent: &LayoutInfo, entities: Vec<LayoutItem>, _:  &mut LayoutRange) -> LayoutOutput {
         let margin = parent.margin;
         let dim = parent.dimension;
+        let items = if parent.direction.is_rtl() {entities.clone()} else {Vec::new()};
         paragraph::<D1, D2>(dim, margin, entities).normalized()
+            .with_direction(parent.direction, &items)
     }
 
     fn dyn_clone(&self) -> Box<dyn Layout> {
@@ -62,6 +70,23 @@ fn trim<T>(slice: &[T], mut f: impl FnMut(&T) -> bool) -> &[T]{
     &slice[min..max]
 }
 
+/// Shared ascent and descent of baseline aligned items, only if `D` is horizontal.
+fn baseline_extent<D: Direction>(items: &[LayoutItem]) -> Option<(f32, f32)> {
+    if D::unit().x == 0.0 {
+        return None;
+    }
+    items.iter()
+        .filter_map(|x| x.baseline.map(|b| (b, x.dimension.y - b)))
+        .reduce(|(a1, d1), (a2, d2)| (a1.max(a2), d1.max(d2)))
+}
+
+/// Height of the line box of baseline aligned items on the `D2` axis.
+fn baseline_height<D1: Direction, D2: Direction>(items: &[LayoutItem]) -> Vec2 {
+    baseline_extent::<D1>(items)
+        .map(|(ascent, descent)| D2::main(Vec2::splat(ascent + descent)).abs())
+        .unwrap_or(Vec2::ZERO)
+}
+
 pub(crate) fn stack<D: Direction>(
     margin: Vec2,
     items: &[LayoutItem]
@@ -72,7 +97,19 @@ pub(crate) fn stack<D: Direction>(
     let line_height = D::side_vec(1.0);
     let mut max_len = Vec2::ZERO;
     let items = trim(items, |x| x.control == LayoutControl::WhiteSpace);
+    if items.is_empty() {
+        return LayoutOutput {
+            entity_anchors: result,
+            dimension: Vec2::ZERO,
+            max_count: 0,
+        }
+    }
     for item in items {
+        // Gaps replace the margin instead of adding to it.
+        if let Some(gap) = item.gap() {
+            cursor += D::main(gap);
+            continue;
+        }
         cursor += margin;
 
         let width = D::main(item.dimension);
@@ -93,7 +130,7 @@ pub(crate) fn stack<D: Direction>(
     }
     LayoutOutput {
         entity_anchors: result,
-        dimension: cursor.abs() + height_mult,
+        dimension: cursor.abs() + D::side(max_len),
         max_count: items.len()
     }
 }
@@ -135,28 +172,58 @@ pub(crate) fn span<D: StretchDir>(
     let mid = trim(&items[neg_len..neg_len + mid_len], |x| x.control == LayoutControl::WhiteSpace);
     let pos = trim(&items[neg_len + mid_len..neg_len + mid_len + pos_len], |x| x.control == LayoutControl::WhiteSpace);
 
+    let baseline = baseline_extent::<D>(items);
+    let place = |item: &LayoutItem, cell_size: Vec2| {
+        let anchor = item.anchor.as_vec() + 0.5;
+        match (baseline, item.baseline) {
+            (Some((ascent, descent)), Some(ascent_item)) => {
+                // Center the shared line box, then put the item's baseline on the line's baseline.
+                let height = item.dimension.y;
+                let top = (cell_size.y + ascent + descent) / 2.0 - ascent + ascent_item;
+                Vec2::new(cell_size.x * anchor.x, top - height + height * anchor.y)
+            },
+            _ => cell_size * anchor,
+        }
+    };
+
     let mut neg_cursor = Vec2::ZERO;
     let mut mid_cursor = Vec2::ZERO;
     let mut pos_cursor = Vec2::ZERO;
 
     for item in neg{
+        if let Some(gap) = item.gap() {
+            neg_cursor += D::Pos::main(gap);
+            continue;
+        }
         let cell_size = D::Pos::main(item.dimension) + minor_dim;
-        result.push((item.entity, neg_cursor + cell_size * (item.anchor.as_vec() + 0.5)));
+        result.push((item.entity, neg_cursor + place(item, cell_size)));
         neg_cursor += D::Pos::main(item.dimension)
     }
 
     for item in mid{
+        if let Some(gap) = item.gap() {
+            mid_cursor += D::Pos::main(gap);
+            continue;
+        }
         let cell_size = D::Pos::main(item.dimension) + minor_dim;
-        result.push((item.entity, mid_cursor + cell_size * (item.anchor.as_vec() + 0.5)));
+        result.push((item.entity, mid_cursor + place(item, cell_size)));
         mid_cursor += D::Pos::main(item.dimension)
     }
 
     for item in pos{
+        if let Some(gap) = item.gap() {
+            pos_cursor += D::Pos::main(gap);
+            continue;
+        }
         let cell_size = D::Pos::main(item.dimension) + minor_dim;
-        result.push((item.entity, pos_cursor + cell_size * (item.anchor.as_vec() + 0.5)));
+        result.push((item.entity, pos_cursor + place(item, cell_size)));
         pos_cursor += D::Pos::main(item.dimension)
     }
 
+    let neg_count = neg.iter().filter(|x| x.gap().is_none()).count();
+    let mid_count = mid.iter().filter(|x| x.gap().is_none()).count();
+    let pos_count = pos.iter().filter(|x| x.gap().is_none()).count();
+
     let margin = if D::STRETCH {
         if result.len() <= 1 {
             Vec2::ZERO
@@ -168,9 +235,9 @@ pub(crate) fn span<D: StretchDir>(
         D::Pos::main(margin)
     };
 
-    neg_cursor += margin * neg.len().saturating_sub(1) as f32;
-    mid_cursor += margin * mid.len().saturating_sub(1) as f32;
-    pos_cursor += margin * pos.len().saturating_sub(1) as f32;
+    neg_cursor += margin * neg_count.saturating_sub(1) as f32;
+    mid_cursor += margin * mid_count.saturating_sub(1) as f32;
+    pos_cursor += margin * pos_count.saturating_sub(1) as f32;
 
     let mut neg_index = 0.0;
     let mut mid_index = 0.0;
@@ -181,9 +248,9 @@ pub(crate) fn span<D: StretchDir>(
     let pos_offset = major_dim - pos_len;
     let mid_offset = (major_dim + neg_len - pos_len - mid_cursor) / 2.0;
 
-    let categories = repeat(Trinary::Neg).take(neg.len())
-        .chain(repeat(Trinary::Mid).take(mid.len()))
-        .chain(repeat(Trinary::Pos).take(pos.len()));
+    let categories = repeat(Trinary::Neg).take(neg_count)
+        .chain(repeat(Trinary::Mid).take(mid_count))
+        .chain(repeat(Trinary::Pos).take(pos_count));
 
     for ((_, pos), category) in result.iter_mut().zip(categories) {
         match category {
@@ -228,7 +295,8 @@ pub(crate) fn paragraph<D1: StretchDir, D2: Direction>(
             last_linebreak = false;
             let line_height = buffer.iter()
                 .map(|x: &LayoutItem| D2::main(x.dimension).abs())
-                .fold(Vec2::ZERO, |a, b| a.max(b));
+                .fold(Vec2::ZERO, |a, b| a.max(b))
+                .max(baseline_height::<D1, D2>(&buffer));
             let line_size = D1::main(size) + line_height;
             let mut span = span::<D1>(line_size, margin, &mut mem::take(&mut buffer));
             let line_height = if item.control == LayoutControl::LinebreakMarker {
@@ -257,7 +325,8 @@ pub(crate) fn paragraph<D1: StretchDir, D2: Direction>(
     if !buffer.is_empty() {
         let line_height = buffer.iter()
             .map(|x: &LayoutItem| D2::main(x.dimension).abs())
-            .fold(Vec2::ZERO, |a, b| a.max(b));
+            .fold(Vec2::ZERO, |a, b| a.max(b))
+            .max(baseline_height::<D1, D2>(&buffer));
         let line_size = D1::main(size) + line_height;
         let mut span = span::<D1>(line_size, margin, &mut buffer);
         cursor += D2::main(line_height).min(Vec2::ZERO);
@@ -281,3 +350,103 @@ pub(crate) fn paragraph<D1: StretchDir, D2: Direction>(
         max_count: lines
     }
 }
+
+#[cfg(test)]
+mod test {
+    use bevy::{ecs::entity::Entity, math::Vec2};
+    use crate::Anchor;
+    use crate::layout::{Layout, LayoutControl, LayoutDirection, LayoutInfo, LayoutItem, LayoutRange, StackLayout};
+
+    fn item(id: u32, dimension: Vec2) -> LayoutItem {
+        LayoutItem {
+            entity: Entity::from_raw(id),
+            anchor: Anchor::CENTER,
+            dimension,
+            control: LayoutControl::None,
+            baseline: None,
+        }
+    }
+
+    fn info(margin: Vec2) -> LayoutInfo {
+        LayoutInfo {
+            dimension: Vec2::ZERO,
+            em: 16.0,
+            rem: 16.0,
+            margin,
+            direction: LayoutDirection::LeftToRight,
+        }
+    }
+
+    #[test]
+    fn stack_size_with_margin() {
+        let items = vec![
+            item(0, Vec2::new(40.0, 10.0)),
+            item(1, Vec2::new(50.0, 20.0)),
+            item(2, Vec2::new(60.0, 30.0)),
+        ];
+        let out = StackLayout::HSTACK.place(&info(Vec2::new(5.0, 0.0)), items, &mut LayoutRange::All);
+        assert_eq!(out.dimension, Vec2::new(160.0, 30.0));
+        assert_eq!(out.entity_anchors.len(), 3);
+        assert!(out.entity_anchors[0].1.abs_diff_eq(Vec2::new(20.0 / 160.0 - 0.5, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn nested_stack_size() {
+        let inner = StackLayout::HSTACK.place(&info(Vec2::new(5.0, 0.0)), vec![
+            item(0, Vec2::new(40.0, 10.0)),
+            item(1, Vec2::new(50.0, 20.0)),
+        ], &mut LayoutRange::All);
+        assert_eq!(inner.dimension, Vec2::new(95.0, 20.0));
+        let outer = StackLayout::VSTACK.place(&info(Vec2::new(0.0, 4.0)), vec![
+            item(2, inner.dimension),
+            item(3, Vec2::new(20.0, 20.0)),
+        ], &mut LayoutRange::All);
+        assert_eq!(outer.dimension, Vec2::new(95.0, 44.0));
+        // top to bottom
+        assert!(outer.entity_anchors[0].1.y > outer.entity_anchors[1].1.y);
+    }
+
+    fn gap(id: u32, dimension: Vec2) -> LayoutItem {
+        LayoutItem {
+            control: LayoutControl::LinebreakMarker,
+            ..item(id, dimension)
+        }
+    }
+
+    #[test]
+    fn vstack_gap_in_middle() {
+        let items = vec![
+            item(0, Vec2::new(20.0, 10.0)),
+            gap(1, Vec2::new(0.0, 6.0)),
+            item(2, Vec2::new(20.0, 10.0)),
+        ];
+        let out = StackLayout::VSTACK.place(&info(Vec2::new(0.0, 4.0)), items, &mut LayoutRange::All);
+        assert_eq!(out.dimension, Vec2::new(20.0, 30.0));
+        assert_eq!(out.entity_anchors.len(), 2);
+        assert!(out.entity_anchors[0].1.abs_diff_eq(Vec2::new(0.0, 0.5 - 5.0 / 30.0), 1e-5));
+        assert!(out.entity_anchors[1].1.abs_diff_eq(Vec2::new(0.0, 5.0 / 30.0 - 0.5), 1e-5));
+    }
+
+    #[test]
+    fn vstack_empty_gap_keeps_margin() {
+        let with_gap = StackLayout::VSTACK.place(&info(Vec2::new(0.0, 4.0)), vec![
+            item(0, Vec2::new(20.0, 10.0)),
+            gap(1, Vec2::ZERO),
+            item(2, Vec2::new(20.0, 10.0)),
+        ], &mut LayoutRange::All);
+        let without_gap = StackLayout::VSTACK.place(&info(Vec2::new(0.0, 4.0)), vec![
+            item(0, Vec2::new(20.0, 10.0)),
+            item(2, Vec2::new(20.0, 10.0)),
+        ], &mut LayoutRange::All);
+        assert_eq!(with_gap.dimension, Vec2::new(20.0, 24.0));
+        assert_eq!(with_gap.dimension, without_gap.dimension);
+        assert_eq!(with_gap.entity_anchors, without_gap.entity_anchors);
+    }
+
+    #[test]
+    fn empty_stack_size() {
+        let out = StackLayout::VSTACK.place(&info(Vec2::new(4.0, 4.0)), Vec::new(), &mut LayoutRange::All);
+        assert_eq!(out.dimension, Vec2::ZERO);
+        assert!(out.entity_anchors.is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::ecs::{change_detection::DetectChanges, component::Component, entity::Entity, world::World};
+use bevy::hierarchy::Children;
+use bevy::math::Vec2;
+
+use crate::anim::{Easing, Interpolate, Offset};
+use crate::util::recompute_layout;
+use crate::{RotatedRect, Transform2D};
+
+use super::{Container, LayoutControl, LayoutObject};
+
+/// Animate children between the old and new layout when the layout of a [`Container`] is switched.
+///
+/// Switch layouts with [`transition_to`](LayoutTransition::transition_to) instead of
+/// writing to `Container` directly. Both layouts are computed on the frame of the switch,
+/// and each child's `Offset` is interpolated from its old position to its new one.
+///
+/// * Children added during the transition are placed directly.
+/// * Children removed during the transition are skipped.
+/// * Children whose offset is not in pixels are placed directly.
+///
+/// # Cost
+///
+/// Each switch recomputes the entire tree once with [`recompute_layout`].
+#[derive(Debug, Clone, Component)]
+pub struct LayoutTransition {
+    /// Duration of the transition in seconds.
+    pub time: f32,
+    /// Curve of the `Interpolate<Offset>` inserted if not present.
+    pub curve: Easing,
+    pending: Option<LayoutObject>,
+}
+
+impl LayoutTransition {
+    pub fn new(time: f32) -> Self {
+        Self {
+            time,
+            curve: Easing::Linear,
+            pending: None,
+        }
+    }
+
+    pub fn with_curve(mut self, curve: Easing) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Switch to a new layout on the next layout pass.
+    pub fn transition_to(&mut self, layout: impl Into<LayoutObject>) {
+        self.pending = Some(layout.into());
+    }
+}
+
+/// Positions of laid out children in their parent's unscaled local space.
+fn child_positions(world: &World, parent: Entity) -> Vec<(Entity, Vec2)> {
+    let Some(rect) = world.get::<RotatedRect>(parent) else {return Vec::new()};
+    let Some(children) = world.get::<Children>(parent) else {return Vec::new()};
+    children.iter().filter_map(|child| {
+        if world.get::<LayoutControl>(*child) == Some(&LayoutControl::IgnoreLayout) {
+            return None;
+        }
+        let child_rect = world.get_entity(*child)?.get_ref::<RotatedRect>()?;
+        // Not placed yet, no previous position.
+        if child_rect.is_added() {
+            return None;
+        }
+        Some((*child, rect.local_space(child_rect.center()) / rect.scale))
+    }).collect()
+}
+
+pub(crate) fn layout_transition(world: &mut World) {
+    let mut pending = Vec::new();
+    let mut query = world.query::<(Entity, &mut LayoutTransition)>();
+    for (entity, mut transition) in query.iter_mut(world) {
+        if let Some(layout) = transition.pending.take() {
+            pending.push((entity, layout, transition.curve, transition.time));
+        }
+    }
+    if pending.is_empty() {
+        return;
+    }
+    let mut transitions = Vec::new();
+    for (entity, layout, curve, time) in pending {
+        let Some(mut container) = world.get_mut::<Container>(entity) else {continue};
+        container.layout = layout;
+        transitions.push((entity, child_positions(world, entity), curve, time));
+    }
+    recompute_layout(world);
+    for (entity, old, curve, time) in transitions {
+        let new = child_positions(world, entity);
+        for (child, old) in old {
+            let Some((_, new)) = new.iter().find(|(e, _)| *e == child) else {continue};
+            let delta = old - *new;
+            if delta == Vec2::ZERO {
+                continue;
+            }
+            let Some(mut child) = world.get_entity_mut(child) else {continue};
+            let Some(mut transform) = child.get_mut::<Transform2D>() else {continue};
+            let Some(offset) = transform.offset.get_pixels() else {continue};
+            let start = offset + delta;
+            transform.offset.edit_raw(|x| *x = start);
+            match child.get_mut::<Interpolate<Offset>>() {
+                Some(mut interpolate) => {
+                    let target = interpolate.target();
+                    interpolate.set(start);
+                    interpolate.interpolate_with_time((start, target), time);
+                },
+                None => {
+                    let mut interpolate = Interpolate::<Offset>::new(curve, start, time);
+                    interpolate.interpolate_with_time((start, offset), time);
+                    child.insert(interpolate);
+                },
+            }
+        }
+    }
+}
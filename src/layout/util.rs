@@ -5,6 +5,7 @@ use bevy::ecs::entity::Entity;
 use bevy::math::bool;
 use bevy::prelude::Vec2;
 use bevy::prelude::Reflect;
+use bevy::ecs::{component::Component, system::Resource};
 
 use crate::{layout::LayoutControl, Anchor};
 
@@ -288,6 +289,26 @@ impl LayoutDir {
     }
 }
 
+/// Reading direction of horizontal layouts, i.e. whether `Start` is on the left or the right.
+///
+/// As a resource this is the global default, as a component on a
+/// [`Container`](crate::layout::Container) this overrides the default.
+///
+/// Currently respected by [`StackLayout`](crate::layout::StackLayout),
+/// [`SpanLayout`](crate::layout::SpanLayout) and [`ParagraphLayout`](crate::layout::ParagraphLayout).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Resource, Component, Reflect)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub fn is_rtl(&self) -> bool {
+        matches!(self, Self::RightToLeft)
+    }
+}
+
 impl From<LayoutDir> for Axis {
     fn from(value: LayoutDir) -> Self {
         match value {
@@ -354,8 +375,11 @@ impl From<&LayoutDir> for Binary {
 }
 
 /// Info for positioning an item in a [`Container`].
+///
+/// Construct with [`LayoutItem::new`], more fields may be added in the future.
 #[doc(hidden)]
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct LayoutItem {
     /// entity of the item
     pub entity: Entity,
@@ -365,6 +389,25 @@ pub struct LayoutItem {
     pub dimension: Vec2,
     /// Force a linebreak on or after this item.
     pub control: LayoutControl,
+    /// Distance from the top of the item to its baseline, if aligned by [`BaselineAlign`](crate::BaselineAlign).
+    pub baseline: Option<f32>,
+}
+
+impl LayoutItem {
+    pub const fn new(entity: Entity, anchor: Anchor, dimension: Vec2, control: LayoutControl) -> Self {
+        Self { entity, anchor, dimension, control, baseline: None }
+    }
+
+    /// Set the distance from the top of the item to its baseline.
+    pub const fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// If this is a [`LinebreakMarker`](LayoutControl::LinebreakMarker), obtain its dimension as a gap.
+    pub fn gap(&self) -> Option<Vec2> {
+        (self.control == LayoutControl::LinebreakMarker).then_some(self.dimension)
+    }
 }
 
 #[doc(hidden)]
@@ -43,5 +43,7 @@ impl bevy::prelude::Plugin for RectrayPlugin {
             .add_plugins(widgets::WidgetsPlugin)
             .add_plugins(bevy_defer::DefaultAsyncPlugin)
         ;
+        #[cfg(feature="deterministic")]
+        util::deterministic_time(app);
     }
 }
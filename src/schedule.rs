@@ -7,6 +7,7 @@ use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
 use crate::RectrayRem;
+use crate::layout::LayoutDirection;
 
 use crate::core::pipeline::{compute_aoui_transforms, compute_aoui_opacity};
 use crate::core::systems::*;
@@ -60,6 +61,7 @@ impl bevy::prelude::Plugin for CorePlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app
             .init_resource::<RectrayRem>()
+            .init_resource::<LayoutDirection>()
             .configure_sets(PreUpdate, EventSet.after(InputSystem))
             .add_systems(PreUpdate, bevy::ecs::prelude::apply_deferred
                 .after(EventSet)
@@ -87,6 +89,11 @@ impl bevy::prelude::Plugin for CorePlugin {
                 .after(propagate_transforms)
                 .after(sync_simple_transforms)
             )
+            .add_systems(PostUpdate, crate::layout::transition::layout_transition
+                .before(LoadInputSet)
+                .after(update_text2d_layout))
+            .add_systems(PostUpdate, follow_target
+                .before(LoadInputSet))
             .add_systems(PostUpdate, (
                 set_occluded,
                 copy_anchor,
@@ -94,6 +101,7 @@ impl bevy::prelude::Plugin for CorePlugin {
                 copy_dimension_sprite,
                 copy_dimension_text,
                 copy_dimension_atlas,
+                copy_text_baseline,
             ).in_set(LoadInputSet))
             .add_systems(PostUpdate, (
                 compute_aoui_transforms::<PrimaryWindow>,
@@ -101,11 +109,15 @@ impl bevy::prelude::Plugin for CorePlugin {
             ).in_set(PipelineSet))
             .add_systems(PostUpdate, (
                 sync_dimension_sprite,
+                sync_dimension_sprite_fit,
+                sync_tile_mode,
                 sync_dimension_text_bounds,
-                sync_em_text,
+                sync_em::<Text>,
                 sync_opacity_vis,
                 sync_opacity_sprite,
                 sync_opacity_text,
+                scale_to_fit,
+                send_size_changed,
             ).in_set(StoreOutputSet))
             .add_systems(PostUpdate, (
                 build_mesh_2d_global_transform,
@@ -1,15 +1,19 @@
 use std::borrow::Borrow;
 use std::marker::PhantomData;
 
-use bevy::ecs::{entity::Entity, bundle::Bundle, component::Component};
+use bevy::ecs::{entity::Entity, bundle::Bundle, component::Component, world::World};
+use bevy::ecs::query::{With, Without};
+use bevy_defer::signals::Signals;
 use bevy::ecs::system::{Command, Commands, EntityCommands, Res, Resource, SystemParam};
-use bevy::hierarchy::{Children, DespawnRecursive, BuildChildren, DespawnRecursiveExt};
+use bevy::hierarchy::{Children, Parent, DespawnRecursive, BuildChildren, BuildWorldChildren, DespawnRecursiveExt};
 use bevy::render::texture::{Image, BevyDefault};
 use bevy::render::render_resource::{TextureDescriptor, Extent3d, TextureDimension, TextureUsages};
 use bevy::asset::{AssetServer, Asset, Handle, AssetPath};
 use bevy::utils::HashMap;
 use bevy_defer::{AsObject, Object, signals::{SignalData, TypedSignal}};
 use parking_lot::RwLock;
+use crate::Transform2D;
+use crate::core::pipeline::Z_INCREMENT;
 use crate::util::{CloneSplit, Widget};
 use crate::widgets::button::RadioButton;
 
@@ -111,6 +115,11 @@ impl<'w, 's> RCommands<'w, 's> {
         widget.build(self, arg)
     }
 
+    /// Build a widget for each item, returns the entities in order for parenting.
+    pub fn spawn_children_from<T>(&mut self, widget: &WidgetBuilder<T>, items: impl IntoIterator<Item = T>) -> Vec<Entity> {
+        items.into_iter().map(|item| widget.build(self, item)).collect()
+    }
+
 
     /// Created a tracked radio button group.
     pub fn radio_button_group<T: AsObject, S: CloneSplit<RadioButton>>(&self, default: T) -> S {
@@ -156,6 +165,212 @@ impl<'w, 's> RCommands<'w, 's> {
 
         self.commands.add(DespawnDescendantsWith::<T>(entity, PhantomData))
     }
+
+    /// Recursively despawn all entities with a specific component in the world.
+    ///
+    /// Entities nested in another matching entity are despawned with their ancestor.
+    pub fn despawn_all_with<T: Component>(&mut self) {
+        pub struct DespawnAllWith<T: Component>(PhantomData<T>);
+        impl<T: Component> Command for DespawnAllWith<T> {
+            fn apply(self, world: &mut World) {
+                let entities: Vec<_> = world.query_filtered::<Entity, With<T>>()
+                    .iter(world)
+                    .collect();
+                let roots: Vec<_> = entities.into_iter()
+                    .filter(|entity| {
+                        let mut current = *entity;
+                        while let Some(parent) = world.get::<Parent>(current) {
+                            current = parent.get();
+                            if world.get::<T>(current).is_some() {
+                                return false;
+                            }
+                        }
+                        true
+                    })
+                    .collect();
+                for entity in roots {
+                    DespawnRecursive {entity}.apply(world);
+                }
+            }
+        }
+
+        self.commands.add(DespawnAllWith::<T>(PhantomData))
+    }
+
+    /// Deep clone an entity and its descendants, returns the new root.
+    ///
+    /// The new root has no parent. Signals are not cloned,
+    /// see [`clone_subtree_with_signals`](Self::clone_subtree_with_signals).
+    pub fn clone_subtree(&mut self, entity: Entity) -> Entity {
+        let target = self.commands.spawn_empty().id();
+        self.commands.add(CloneSubtree { source: entity, target, share_signals: false });
+        target
+    }
+
+    /// Deep clone an entity and its descendants, returns the new root.
+    ///
+    /// The clones share senders and receivers with the original.
+    pub fn clone_subtree_with_signals(&mut self, entity: Entity) -> Entity {
+        let target = self.commands.spawn_empty().id();
+        self.commands.add(CloneSubtree { source: entity, target, share_signals: true });
+        target
+    }
+
+    /// Move an entity's `z` above all its siblings, or above all roots if it has no parent.
+    pub fn bring_to_front(&mut self, entity: Entity) {
+        self.commands.add(BringToFront(entity))
+    }
+
+    /// Move an entity's `z` below all its siblings, or below all roots if it has no parent.
+    pub fn send_to_back(&mut self, entity: Entity) {
+        self.commands.add(SendToBack(entity))
+    }
+}
+
+macro_rules! clone_components {
+    ($world: expr, $source: expr, $target: expr, [$($ty: ty),* $(,)?]) => {
+        $(if let Some(component) = $world.get::<$ty>($source).cloned() {
+            $world.entity_mut($target).insert(component);
+        })*
+    };
+}
+
+/// [`Command`] that deep clones an entity subtree into `target`.
+///
+/// Only known components of `bevy` and `bevy_rectray` are cloned,
+/// this includes transforms, dimensions, colors, sprites, texts and button contexts.
+/// Async systems and signal adaptors are never cloned.
+#[derive(Debug, Clone, Copy)]
+pub struct CloneSubtree {
+    pub source: Entity,
+    pub target: Entity,
+    /// If set, clones share senders and receivers with the original.
+    ///
+    /// `RadioButton` shares its group with the original, so it is only cloned if set.
+    pub share_signals: bool,
+}
+
+impl Command for CloneSubtree {
+    fn apply(self, world: &mut World) {
+        use bevy::core::Name;
+        use bevy::transform::components::{Transform, GlobalTransform};
+        use bevy::render::view::{Visibility, InheritedVisibility, ViewVisibility, RenderLayers};
+        use bevy::sprite::{Anchor, ColorMaterial, Mesh2dHandle, Sprite, TextureAtlas};
+        use bevy::text::{Text, Text2dBounds, TextLayoutInfo};
+        use crate::{Dimension, DimensionData, RotatedRect, BuildTransform, BuildMeshTransform};
+        use crate::{Detach, Opacity, Coloring, Clipping, Hitbox, SpriteFit, TileMode, IgnoreAlpha, TextMinSize};
+        use crate::events::EventFlags;
+        use crate::layout::{Container, LayoutControl};
+        use crate::widgets::button::{Button, CheckButton, Payload};
+        use crate::widgets::util::{PropagateFocus, SetCursor};
+
+        if world.get_entity(self.source).is_none() || world.get_entity(self.target).is_none() {
+            return;
+        }
+        clone_components!(world, self.source, self.target, [
+            Name, Transform, GlobalTransform,
+            Visibility, InheritedVisibility, ViewVisibility, RenderLayers,
+            Transform2D, Dimension, DimensionData, RotatedRect,
+            BuildTransform, BuildMeshTransform, Detach,
+            Opacity, Coloring, Clipping, Hitbox, EventFlags,
+            Container, LayoutControl,
+            Sprite, Anchor, Handle<Image>, TextureAtlas, SpriteFit, TileMode, IgnoreAlpha,
+            Mesh2dHandle, Handle<ColorMaterial>,
+            Text, Text2dBounds, TextLayoutInfo, TextMinSize,
+            Button, CheckButton, Payload, PropagateFocus, SetCursor,
+        ]);
+        if self.share_signals {
+            clone_components!(world, self.source, self.target, [RadioButton]);
+            if let Some(signals) = world.get::<Signals>(self.source) {
+                let cloned = Signals {
+                    senders: signals.senders.clone(),
+                    receivers: signals.receivers.clone(),
+                    ..Default::default()
+                };
+                world.entity_mut(self.target).insert(cloned);
+            }
+        }
+        let children = world.get::<Children>(self.source)
+            .map(|children| children.to_vec())
+            .unwrap_or_default();
+        for child in children {
+            let target = world.spawn_empty().id();
+            CloneSubtree { source: child, target, ..self }.apply(world);
+            world.entity_mut(self.target).add_child(target);
+        }
+    }
+}
+
+/// Difference in `z` between the moved entity and the front or back of its siblings.
+const Z_ORDER_STEP: f32 = 0.01;
+
+/// Effective `z` of a [`Transform2D`], since `0` means placed slightly above the parent.
+fn effective_z(z: f32) -> f32 {
+    if z == 0.0 { Z_INCREMENT } else { z }
+}
+
+/// Obtain effective `z` of the siblings of an entity, or of other roots if it has no parent.
+fn sibling_z(world: &mut World, entity: Entity) -> Vec<f32> {
+    let siblings = match world.get::<Parent>(entity) {
+        Some(parent) => world.get::<Children>(parent.get())
+            .map(|children| children.to_vec())
+            .unwrap_or_default(),
+        None => world.query_filtered::<Entity, (With<Transform2D>, Without<Parent>)>()
+            .iter(world)
+            .collect(),
+    };
+    siblings.into_iter()
+        .filter(|x| *x != entity)
+        .filter_map(|x| world.get::<Transform2D>(x))
+        .map(|x| effective_z(x.z))
+        .collect()
+}
+
+/// [`Command`] that moves an entity's [`Transform2D::z`] above all its siblings.
+///
+/// Only the entity's `z` is modified, so the relative order of its siblings is unchanged.
+/// If the entity ties with the front sibling, it is moved strictly above it.
+#[derive(Debug, Clone, Copy)]
+pub struct BringToFront(pub Entity);
+
+impl Command for BringToFront {
+    fn apply(self, world: &mut World) {
+        if world.get::<Transform2D>(self.0).is_none() {
+            return;
+        }
+        let max = sibling_z(world, self.0).into_iter().reduce(f32::max);
+        let Some(mut transform) = world.get_mut::<Transform2D>(self.0) else {return};
+        if let Some(max) = max {
+            if effective_z(transform.z) <= max {
+                // a result of `0` becomes `Z_INCREMENT`, which is still above `max`.
+                transform.z = max + Z_ORDER_STEP;
+            }
+        }
+    }
+}
+
+/// [`Command`] that moves an entity's [`Transform2D::z`] below all its siblings.
+///
+/// Only the entity's `z` is modified, so the relative order of its siblings is unchanged.
+/// If the entity ties with the back sibling, it is moved strictly below it.
+#[derive(Debug, Clone, Copy)]
+pub struct SendToBack(pub Entity);
+
+impl Command for SendToBack {
+    fn apply(self, world: &mut World) {
+        if world.get::<Transform2D>(self.0).is_none() {
+            return;
+        }
+        let min = sibling_z(world, self.0).into_iter().reduce(f32::min);
+        let Some(mut transform) = world.get_mut::<Transform2D>(self.0) else {return};
+        if let Some(min) = min {
+            if effective_z(transform.z) >= min {
+                let z = min - Z_ORDER_STEP;
+                // `0` would be treated as `Z_INCREMENT`, which is not below `min`.
+                transform.z = if z == 0.0 { -Z_ORDER_STEP } else { z };
+            }
+        }
+    }
 }
 
 impl AsRef<AssetServer> for RCommands<'_, '_> {
@@ -169,3 +384,32 @@ impl<'w, 's> AsMut<Commands<'w, 's>> for RCommands<'w, 's> {
         &mut self.commands
     }
 }
+
+#[cfg(test)]
+mod test {
+    use bevy::app::App;
+    use bevy::asset::AssetPlugin;
+    use bevy::core::Name;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::MinimalPlugins;
+    use crate::util::WidgetBuilder;
+    use super::{RCommands, SignalPool};
+
+    #[test]
+    fn spawn_children_from_rows() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_resource::<SignalPool>();
+        let rows = app.world.run_system_once(|mut commands: RCommands| {
+            let row = WidgetBuilder::new(|commands: &mut RCommands, index: usize| {
+                commands.commands().spawn(Name::new(format!("row {index}"))).id()
+            });
+            commands.spawn_children_from(&row, 0..100)
+        });
+        assert_eq!(rows.len(), 100);
+        for (index, row) in rows.into_iter().enumerate() {
+            let name = app.world.get::<Name>(row).map(|x| x.as_str().to_owned());
+            assert_eq!(name, Some(format!("row {index}")));
+        }
+    }
+}
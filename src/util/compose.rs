@@ -5,7 +5,7 @@ use bevy_defer::AsyncSystems;
 
 use crate::dsl::prelude::Signals;
 use crate::events::EventFlags;
-use bevy_defer::signals::{SignalId, SignalMapper, TypedSignal};
+use bevy_defer::signals::{RoleSignal, SignalId, SignalMapper, TypedSignal};
 
 /// A component that can be either inserted or composed.
 pub trait ComponentCompose: Component {
@@ -32,6 +32,54 @@ impl ComponentCompose for Signals {
     }
 }
 
+/// A [`RoleSignal`] or a tuple of them, converted into a single [`Signals`].
+///
+/// Senders and receivers of the same type can be mixed,
+/// a later sender or receiver of the same type and role replaces the earlier one.
+pub trait IntoSignalTuple {
+    fn into_signal_tuple(self) -> Signals;
+}
+
+impl IntoSignalTuple for Signals {
+    fn into_signal_tuple(self) -> Signals {
+        self
+    }
+}
+
+impl<T: SignalId> IntoSignalTuple for RoleSignal<T> {
+    fn into_signal_tuple(self) -> Signals {
+        self.into_signals()
+    }
+}
+
+macro_rules! impl_signal_tuple {
+    ($first: ident $(,$rest: ident)*) => {
+        impl<$first: IntoSignalTuple $(,$rest: IntoSignalTuple)*> IntoSignalTuple for ($first, $($rest,)*) {
+            #[allow(non_snake_case)]
+            fn into_signal_tuple(self) -> Signals {
+                let ($first, $($rest,)*) = self;
+                #[allow(unused_mut)]
+                let mut signals = $first.into_signal_tuple();
+                $(ComponentCompose::compose(&mut signals, $rest.into_signal_tuple());)*
+                signals
+            }
+        }
+    };
+}
+
+impl_signal_tuple!(A);
+impl_signal_tuple!(A, B);
+impl_signal_tuple!(A, B, C);
+impl_signal_tuple!(A, B, C, D);
+impl_signal_tuple!(A, B, C, D, E);
+impl_signal_tuple!(A, B, C, D, E, F);
+impl_signal_tuple!(A, B, C, D, E, F, G);
+impl_signal_tuple!(A, B, C, D, E, F, G, H);
+impl_signal_tuple!(A, B, C, D, E, F, G, H, I);
+impl_signal_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_signal_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_signal_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 pub(crate) struct ComposeInsert<T: ComponentCompose>(pub Entity, pub T);
 
 impl<T: ComponentCompose> Command for ComposeInsert<T> {
@@ -96,6 +144,8 @@ pub trait ComposeExtension {
     fn add_sender<T: SignalId>(&mut self, component: TypedSignal<T::Data>) -> &mut Self;
     fn add_receiver<T: SignalId>(&mut self, component: TypedSignal<T::Data>) -> &mut Self;
     fn add_adaptor<From: SignalId, To: SignalId>(&mut self, adaptor: impl Fn(From::Data) -> To::Data + Clone + Send + Sync + 'static)  -> &mut Self;
+    /// Add a [`RoleSignal`] or a tuple of them.
+    fn add_signals(&mut self, signals: impl IntoSignalTuple) -> &mut Self;
 }
 
 impl ComposeExtension for EntityCommands<'_> {
@@ -135,4 +185,8 @@ impl ComposeExtension for EntityCommands<'_> {
         self.commands().add(AddSignalAdaptor::<From, To>(entity, SignalMapper::new::<From, To>(adaptor), PhantomData));
         self
     }
+
+    fn add_signals(&mut self, signals: impl IntoSignalTuple) -> &mut Self {
+        self.compose(signals.into_signal_tuple())
+    }
 }
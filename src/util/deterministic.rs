@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use bevy::app::App;
+use bevy::time::{Fixed, Time, TimeUpdateStrategy};
+
+/// Time step of a frame with the `deterministic` feature, `1/60` seconds.
+pub const DETERMINISTIC_DELTA: Duration = Duration::from_nanos(16_666_667);
+
+/// Make `Time`, `Time<Real>` and `Time<Fixed>` advance by [`DETERMINISTIC_DELTA`]
+/// every frame, so `FixedUpdate` runs exactly once per frame.
+///
+/// Called by `RectrayPlugin` with the `deterministic` feature,
+/// this overwrites the user's fixed timestep.
+pub(crate) fn deterministic_time(app: &mut App) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(DETERMINISTIC_DELTA))
+        .insert_resource(Time::<Fixed>::from_duration(DETERMINISTIC_DELTA));
+}
+
+/// Advance `app` by `frames` frames, each by [`DETERMINISTIC_DELTA`].
+///
+/// Tasks spawned with `bevy_defer` are polled as part of each frame,
+/// a task awaiting a signal or a component resolves in the frame after it is written.
+/// Tasks that wait on other threads or real time are not deterministic.
+///
+/// # Example
+///
+/// ```
+/// # /*
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, RectrayPlugin));
+/// step_frames(&mut app, 30);
+/// # */
+/// ```
+pub fn step_frames(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::app::App;
+    use bevy::MinimalPlugins;
+    use crate::anim::{AnimationPlugin, Easing, Interpolate};
+    use crate::Opacity;
+    use super::{deterministic_time, step_frames, DETERMINISTIC_DELTA};
+
+    fn fade_for(frames: usize) -> f32 {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AnimationPlugin));
+        deterministic_time(&mut app);
+        let mut interpolate = Interpolate::<Opacity>::new(Easing::Linear, 0.0, 1.0);
+        interpolate.interpolate_to(1.0);
+        let entity = app.world.spawn((Opacity::new(0.0), interpolate)).id();
+        step_frames(&mut app, frames);
+        app.world.get::<Opacity>(entity).unwrap().opacity
+    }
+
+    #[test]
+    fn interpolation_after_frames() {
+        let opacity = fade_for(30);
+        // The first frame has no delta, and `Opacity` is written before the timer advances,
+        // so 30 frames advance the 1 second fade by 28 steps.
+        let expected = 28.0 * DETERMINISTIC_DELTA.as_secs_f32();
+        assert!((opacity - expected).abs() < 1e-5, "{opacity} != {expected}");
+        assert_eq!(opacity, fade_for(30));
+        assert_eq!(fade_for(70), 1.0);
+    }
+}
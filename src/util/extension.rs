@@ -1,5 +1,6 @@
-use bevy::{window::CursorIcon, app::App, math::Vec2};
-use crate::{widgets::util::CursorDefault, events::ScrollScaling, util::DslInto};
+use bevy::{window::CursorIcon, app::{App, Update}, math::Vec2, ecs::{component::Component, schedule::IntoSystemConfigs}};
+use bevy_defer::signals::SignalId;
+use crate::{widgets::util::{CursorDefault, despawn_on_signal, set_cursor, set_cursor_if}, events::{ScrollScaling, ScrollSpeed}, util::DslInto};
 
 /// Extension methods to `World` and `App`
 pub trait WorldExtension {
@@ -9,6 +10,15 @@ pub trait WorldExtension {
 
     /// Register mouse wheel scrolling speed.
     fn register_scrolling_speed(&mut self, line_to_pixels: impl DslInto<Vec2>, speed: impl DslInto<Vec2>) -> &mut Self;
+
+    /// Register mouse wheel speed multipliers for line and pixel based devices.
+    fn register_scroll_speed(&mut self, speed: ScrollSpeed) -> &mut Self;
+
+    /// Register [`DespawnOnSignal`](crate::widgets::util::DespawnOnSignal) for a signal.
+    fn register_despawn_on_signal<T: SignalId>(&mut self) -> &mut Self;
+
+    /// Register [`SetCursorIf`](crate::widgets::util::SetCursorIf) for a component.
+    fn register_set_cursor_if<T: Component + PartialEq>(&mut self) -> &mut Self;
 }
 
 impl WorldExtension for App {
@@ -24,4 +34,16 @@ impl WorldExtension for App {
             pixel_scale: speed.dinto(),
         })
     }
-}
\ No newline at end of file
+
+    fn register_scroll_speed(&mut self, speed: ScrollSpeed) -> &mut Self {
+        self.insert_resource(speed)
+    }
+
+    fn register_despawn_on_signal<T: SignalId>(&mut self) -> &mut Self {
+        self.add_systems(Update, despawn_on_signal::<T>)
+    }
+
+    fn register_set_cursor_if<T: Component + PartialEq>(&mut self) -> &mut Self {
+        self.add_systems(Update, set_cursor_if::<T>.before(set_cursor))
+    }
+}
@@ -0,0 +1,73 @@
+use bevy::ecs::system::{Query, Res, Resource};
+use bevy::gizmos::gizmos::Gizmos;
+use bevy::render::color::Color;
+use bevy::render::view::{InheritedVisibility, RenderLayers};
+
+use crate::{Anchor, RotatedRect, Transform2D};
+
+/// Draws the `RotatedRect` and anchor of every sprite with gizmos, requires feature `gizmos`.
+///
+/// Insert this resource to enable, toggle with `enabled`.
+///
+/// Only sprites whose `RenderLayers` (or the default layer if missing) intersect
+/// `render_layers` are drawn. To draw on a scoped camera, set `render_layers` and
+/// the `render_layers` of bevy's `GizmoConfig` to the camera's layers.
+#[derive(Debug, Clone, Resource)]
+pub struct LayoutGizmos {
+    pub enabled: bool,
+    /// Color of the rect outline.
+    pub rect_color: Color,
+    /// Color of the anchor point.
+    pub anchor_color: Color,
+    /// Radius of the anchor point in pixels.
+    pub anchor_radius: f32,
+    /// Layers of sprites drawn.
+    pub render_layers: RenderLayers,
+}
+
+impl LayoutGizmos {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_render_layers(mut self, layers: RenderLayers) -> Self {
+        self.render_layers = layers;
+        self
+    }
+
+    /// Toggle the overlay on or off.
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+    }
+}
+
+impl Default for LayoutGizmos {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rect_color: Color::GREEN,
+            anchor_color: Color::RED,
+            anchor_radius: 3.0,
+            render_layers: RenderLayers::default(),
+        }
+    }
+}
+
+pub(crate) fn draw_layout_gizmos(
+    config: Option<Res<LayoutGizmos>>,
+    mut gizmos: Gizmos,
+    query: Query<(&RotatedRect, &Transform2D, &InheritedVisibility, Option<&RenderLayers>)>,
+) {
+    let Some(config) = config else {return};
+    if !config.enabled {
+        return;
+    }
+    for (rect, transform, vis, layers) in query.iter() {
+        if !vis.get() || !layers.copied().unwrap_or_default().intersects(&config.render_layers) {
+            continue;
+        }
+        gizmos.rect_2d(rect.center(), rect.rotation, rect.half_dim() * 2.0, config.rect_color);
+        let anchor = rect.anchor(transform.anchor.or(Anchor::CENTER));
+        gizmos.circle_2d(anchor, config.anchor_radius, config.anchor_color);
+    }
+}
@@ -0,0 +1,92 @@
+use std::any::{type_name, TypeId};
+
+use bevy::core::Name;
+use bevy::ecs::{entity::Entity, system::{Query, ResMut, Resource}};
+use bevy::utils::HashMap;
+use bevy_defer::{AsObject, Object};
+use bevy_defer::signals::{Signal, SignalId, Signals, TypedSignal};
+
+/// Lists `Signals` components, their signal ids and last values for debugging, requires feature `debug`.
+///
+/// Insert this resource to enable, entries are refreshed every frame.
+/// Signal ids not registered with [`register`](SignalInspector::register)
+/// are displayed as their `TypeId`.
+#[derive(Debug, Default, Resource)]
+pub struct SignalInspector {
+    names: HashMap<TypeId, &'static str>,
+    entries: Vec<SignalEntry>,
+}
+
+/// Signals of an entity.
+#[derive(Debug, Clone)]
+pub struct SignalEntry {
+    pub entity: Entity,
+    pub name: Option<String>,
+    pub senders: Vec<SignalValue>,
+    pub receivers: Vec<SignalValue>,
+}
+
+/// A signal id and the last value sent through the signal.
+#[derive(Debug, Clone)]
+pub struct SignalValue {
+    pub id: String,
+    /// `Debug` output of the last value, `None` if nothing has been sent.
+    pub value: Option<String>,
+}
+
+impl SignalInspector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Display a signal id by its type name.
+    pub fn with<T: SignalId>(mut self) -> Self {
+        self.register::<T>();
+        self
+    }
+
+    /// Display a signal id by its type name.
+    pub fn register<T: SignalId>(&mut self) {
+        self.names.insert(TypeId::of::<T>(), type_name::<T>());
+    }
+
+    /// Obtain the display name of a signal id.
+    pub fn name_of(&self, id: &TypeId) -> String {
+        match self.names.get(id) {
+            Some(name) => name.to_string(),
+            None => format!("{:?}", id),
+        }
+    }
+
+    fn value_of(&self, id: &TypeId, signal: &Signal<Object>) -> SignalValue {
+        // `peek` does not mark the value as read.
+        let value = TypedSignal::<Object>::from_signal(signal).peek().and_then(|obj| {
+            obj.as_dyn_inner().map(|inner| format!("{:?}", inner))
+        });
+        SignalValue { id: self.name_of(id), value }
+    }
+
+    /// Obtain all entities with `Signals`.
+    pub fn entries(&self) -> &[SignalEntry] {
+        &self.entries
+    }
+
+    /// Obtain the signals of an entity.
+    pub fn get(&self, entity: Entity) -> Option<&SignalEntry> {
+        self.entries.iter().find(|x| x.entity == entity)
+    }
+}
+
+pub(crate) fn inspect_signals(
+    inspector: Option<ResMut<SignalInspector>>,
+    query: Query<(Entity, Option<&Name>, &Signals)>,
+) {
+    let Some(mut inspector) = inspector else {return};
+    let entries = query.iter().map(|(entity, name, signals)| SignalEntry {
+        entity,
+        name: name.map(|x| x.to_string()),
+        senders: signals.senders.iter().map(|(id, signal)| inspector.value_of(id, signal)).collect(),
+        receivers: signals.receivers.iter().map(|(id, signal)| inspector.value_of(id, signal)).collect(),
+    }).collect();
+    inspector.entries = entries;
+}
@@ -0,0 +1,69 @@
+use ab_glyph::{Font as _, ScaleFont};
+use bevy::asset::{Assets, Handle};
+use bevy::ecs::system::{Res, SystemParam};
+use bevy::math::Vec2;
+use bevy::text::Font;
+
+fn measure_line<F: ab_glyph::Font>(font: &impl ScaleFont<F>, string: &str) -> f32 {
+    let mut cursor = 0.0;
+    let mut last = None;
+    for c in string.chars() {
+        let id = font.glyph_id(c);
+        if let Some(last) = last {
+            cursor += font.kern(last, id);
+        }
+        cursor += font.h_advance(id);
+        last = Some(id);
+    }
+    cursor
+}
+
+/// Measure the size of a string in pixels without spawning it.
+///
+/// If `bounds` is specified, wraps on word boundaries
+/// when a line exceeds `bounds.x`, the result is not clamped to `bounds`.
+pub fn measure_text(font: &Font, text: &str, size: f32, bounds: Option<Vec2>) -> Vec2 {
+    let font = font.font.as_scaled(size);
+    let max_width = bounds.map(|x| x.x).unwrap_or(f32::MAX);
+    let space = measure_line(&font, " ");
+    let mut width = 0.0f32;
+    let mut lines = 0;
+    for line in text.split('\n') {
+        lines += 1;
+        let line_width = measure_line(&font, line);
+        if line_width <= max_width {
+            width = width.max(line_width);
+            continue;
+        }
+        let mut cursor = 0.0f32;
+        for word in line.split(' ') {
+            let word_width = measure_line(&font, word);
+            if cursor > 0.0 && cursor + space + word_width > max_width {
+                width = width.max(cursor);
+                lines += 1;
+                cursor = word_width;
+            } else if cursor > 0.0 {
+                cursor += space + word_width;
+            } else {
+                cursor = word_width;
+            }
+        }
+        width = width.max(cursor);
+    }
+    let height = font.height() * lines as f32 + font.line_gap() * (lines - 1) as f32;
+    Vec2::new(width, height)
+}
+
+/// Query for measuring text before building the tree.
+#[derive(SystemParam)]
+pub struct TextMeasure<'w> {
+    fonts: Res<'w, Assets<Font>>,
+}
+
+impl TextMeasure<'_> {
+    /// Measure the size of a string in pixels, returns `None` if the font is not loaded.
+    pub fn measure(&self, text: &str, font: &Handle<Font>, size: f32, bounds: Option<Vec2>) -> Option<Vec2> {
+        let font = self.fonts.get(font)?;
+        Some(measure_text(font, text, size, bounds))
+    }
+}
@@ -7,17 +7,39 @@ mod extension;
 mod mesh;
 mod compose;
 mod queries;
+mod measure;
 mod to_bundle;
 mod fps;
+mod recompute;
+#[cfg(feature="debug")]
+mod inspector;
+#[cfg(feature="gizmos")]
+mod gizmos;
+#[cfg(feature="deterministic")]
+mod deterministic;
 
 pub mod convert;
 
 pub use mesh::mesh_rectangle;
 pub use widget::{Widget, WidgetBuilder, IntoWidgetBuilder};
-pub use commands::{RCommands, signal, SignalPool};
+pub use commands::{RCommands, signal, SignalPool, CloneSubtree, BringToFront, SendToBack};
 pub use cloning::CloneSplit;
 pub use extension::WorldExtension;
 pub use convert::{DslFrom, DslInto};
-pub use compose::{ComponentCompose, ComposeExtension};
+pub use compose::{ComponentCompose, ComposeExtension, IntoSignalTuple};
 pub use queries::*;
-pub use fps::Fps;
\ No newline at end of file
+pub use measure::{measure_text, TextMeasure};
+pub use fps::Fps;
+pub use recompute::{recompute_layout, RecomputeLayout};
+#[cfg(feature="debug")]
+pub use inspector::{SignalInspector, SignalEntry, SignalValue};
+#[cfg(feature="debug")]
+pub(crate) use inspector::inspect_signals;
+#[cfg(feature="gizmos")]
+pub use gizmos::LayoutGizmos;
+#[cfg(feature="deterministic")]
+pub use deterministic::{step_frames, DETERMINISTIC_DELTA};
+#[cfg(feature="deterministic")]
+pub(crate) use deterministic::deterministic_time;
+#[cfg(feature="gizmos")]
+pub(crate) use gizmos::draw_layout_gizmos;
\ No newline at end of file
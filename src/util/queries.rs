@@ -1,11 +1,11 @@
 use std::{iter::Copied, slice::Iter};
 
-use bevy::{hierarchy::Children, math::Vec2, window::{PrimaryWindow, Window}};
+use bevy::{hierarchy::{Children, Parent}, math::Vec2, window::{PrimaryWindow, Window}};
 use bevy::ecs::entity::Entity;
 use bevy::ecs::system::{Query, Res, SystemParam};
 use bevy::ecs::query::{QueryData, With};
 
-use crate::RectrayRem;
+use crate::{DimensionData, RectrayRem, RotatedRect};
 
 /// Query for scaling factor from [`Window`].
 #[derive(SystemParam)]
@@ -51,6 +51,64 @@ impl Rem<'_> {
     }
 }
 
+/// Query for the [`RotatedRect`] of entities relative to their parents.
+///
+/// Useful for custom drag and scroll constraints.
+#[derive(SystemParam)]
+pub struct ParentRelativeRect<'w, 's> {
+    rects: Query<'w, 's, (&'static RotatedRect, Option<&'static Parent>)>,
+}
+
+impl ParentRelativeRect<'_, '_> {
+    /// Obtain the rect of an entity in its parent's local space,
+    /// see [`RotatedRect::relative_to`].
+    ///
+    /// Returns the screen space rect if the entity has no parent with a `RotatedRect`.
+    pub fn get(&self, entity: Entity) -> Option<RotatedRect> {
+        let (rect, parent) = self.rects.get(entity).ok()?;
+        match parent.and_then(|p| self.rects.get(p.get()).ok()) {
+            Some((parent, _)) => Some(rect.relative_to(parent)),
+            None => Some(*rect),
+        }
+    }
+
+    /// Obtain the rect of an entity in an ancestor's local space.
+    pub fn relative_to(&self, entity: Entity, ancestor: Entity) -> Option<RotatedRect> {
+        let (rect, _) = self.rects.get(entity).ok()?;
+        let (ancestor, _) = self.rects.get(ancestor).ok()?;
+        Some(rect.relative_to(ancestor))
+    }
+}
+
+/// Query for resolved `em`, `rem` and size of entities.
+#[derive(SystemParam)]
+pub struct FontMetrics<'w, 's> {
+    rem: Rem<'w>,
+    dimension: Query<'w, 's, &'static DimensionData>,
+}
+
+impl FontMetrics<'_, '_> {
+    /// Obtain `rem` from [`RectrayRem`].
+    pub fn rem(&self) -> f32 {
+        self.rem.get()
+    }
+
+    /// Obtain the resolved `em` of an entity.
+    pub fn em(&self, entity: Entity) -> Option<f32> {
+        self.dimension.get(entity).ok().map(|x| x.em)
+    }
+
+    /// Obtain the resolved `em` and `rem` of an entity.
+    pub fn get(&self, entity: Entity) -> Option<(f32, f32)> {
+        self.em(entity).map(|em| (em, self.rem()))
+    }
+
+    /// Obtain the size of an entity in pixels.
+    pub fn size(&self, entity: Entity) -> Option<Vec2> {
+        self.dimension.get(entity).ok().map(|x| x.size)
+    }
+}
+
 /// Query for children that can also be empty.
 #[derive(QueryData)]
 pub struct ChildIter {
@@ -0,0 +1,45 @@
+use bevy::ecs::system::{Command, RunSystemOnce};
+use bevy::ecs::world::World;
+use bevy::window::PrimaryWindow;
+
+use crate::core::pipeline::{compute_aoui_opacity, compute_aoui_transforms};
+use crate::core::systems::{copy_anchor, copy_anchor_sprite, copy_dimension_atlas, copy_dimension_sprite, copy_dimension_text, copy_text_baseline, set_occluded};
+
+/// Immediately recompute layout outside of `PostUpdate`.
+///
+/// Runs the systems in `LoadInputSet` and `PipelineSet` once, after which
+/// `RotatedRect`, `DimensionData` and `Opacity` are up to date.
+/// `GlobalTransform` and sizes of sprites and texts are only written in `PostUpdate`.
+///
+/// Sizes of images that are not yet loaded are treated as zero,
+/// text is measured with its last `TextLayoutInfo`, which is zero if newly spawned.
+///
+/// # Cost
+///
+/// Every call initializes these systems from scratch and
+/// recomputes the entire tree, not just the subtree that changed.
+/// Avoid calling this every frame.
+pub fn recompute_layout(world: &mut World) {
+    world.run_system_once(set_occluded);
+    world.run_system_once(copy_anchor);
+    world.run_system_once(copy_anchor_sprite);
+    world.run_system_once(copy_dimension_sprite);
+    world.run_system_once(copy_dimension_text);
+    world.run_system_once(copy_dimension_atlas);
+    world.run_system_once(copy_text_baseline);
+    world.run_system_once(compute_aoui_transforms::<PrimaryWindow>);
+    world.run_system_once(compute_aoui_opacity);
+}
+
+/// A [`Command`] that calls [`recompute_layout`].
+///
+/// Use `commands.add(RecomputeLayout)` after spawning a tree,
+/// results are available to systems that run after the commands are applied.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RecomputeLayout;
+
+impl Command for RecomputeLayout {
+    fn apply(self, world: &mut World) {
+        recompute_layout(world)
+    }
+}
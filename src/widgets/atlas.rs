@@ -91,6 +91,9 @@ pub(crate) fn build_deferred_atlas(
                 let Some(image) = image_assets.get(image) else {continue};
                 let mut atlas = TextureAtlasLayout::new_empty(image.size().as_vec2());
                 atlas.textures = mem::take(rectangles);
+                if *index >= atlas.textures.len() {
+                    warn!("Atlas index {} out of bounds, atlas has {} frames.", index, atlas.textures.len());
+                }
                 commands
                     .entity(entity)
                     .remove::<DeferredAtlasBuilder>()
@@ -102,3 +105,118 @@ pub(crate) fn build_deferred_atlas(
         };
     }
 }
+
+#[cfg(feature="atlas_json")]
+pub use json::{AtlasJsonLoader, AtlasJsonError};
+
+#[cfg(feature="atlas_json")]
+mod json {
+    use bevy::asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext};
+    use bevy::log::warn;
+    use bevy::math::{Rect, Vec2};
+    use bevy::sprite::TextureAtlasLayout;
+    use bevy::utils::BoxedFuture;
+    use serde_json::Value;
+
+    /// Loads a [`TextureAtlasLayout`] from `.atlas.json` files.
+    ///
+    /// # Accepts
+    ///
+    /// * `{"size": [w, h], "atlas": [[x, y, w, h], ...]}`
+    /// * Aseprite's JSON array export: `{"frames": [{"frame": {x, y, w, h}}, ...], "meta": {"size": {w, h}}}`
+    ///
+    /// Malformed frames are skipped with a warning.
+    #[derive(Debug, Default)]
+    pub struct AtlasJsonLoader;
+
+    /// Error of [`AtlasJsonLoader`].
+    #[derive(Debug, thiserror::Error)]
+    pub enum AtlasJsonError {
+        #[error("{0}")]
+        Io(#[from] std::io::Error),
+        #[error("{0}")]
+        Json(#[from] serde_json::Error),
+        #[error("Unrecognized atlas format, expected \"atlas\" or \"frames\".")]
+        Format,
+    }
+
+    fn as_vec2(value: &Value) -> Option<Vec2> {
+        match value {
+            Value::Array(arr) => Some(Vec2::new(
+                arr.first()?.as_f64()? as f32,
+                arr.get(1)?.as_f64()? as f32,
+            )),
+            Value::Object(obj) => Some(Vec2::new(
+                obj.get("w")?.as_f64()? as f32,
+                obj.get("h")?.as_f64()? as f32,
+            )),
+            _ => None,
+        }
+    }
+
+    fn as_rect(value: &Value) -> Option<Rect> {
+        let [x, y, w, h] = match value {
+            Value::Array(arr) => [arr.first()?, arr.get(1)?, arr.get(2)?, arr.get(3)?],
+            Value::Object(obj) => [obj.get("x")?, obj.get("y")?, obj.get("w")?, obj.get("h")?],
+            _ => return None,
+        }.map(|x| x.as_f64().unwrap_or(f64::NAN) as f32);
+        if [x, y, w, h].iter().any(|x| x.is_nan()) {
+            return None;
+        }
+        Some(Rect {
+            min: Vec2::new(x, y),
+            max: Vec2::new(x + w, y + h),
+        })
+    }
+
+    fn parse_atlas(value: &Value) -> Result<TextureAtlasLayout, AtlasJsonError> {
+        let (size, frames) = if let Some(atlas) = value.get("atlas") {
+            (value.get("size").and_then(as_vec2), atlas)
+        } else if let Some(frames) = value.get("frames") {
+            (value.get("meta").and_then(|x| x.get("size")).and_then(as_vec2), frames)
+        } else {
+            return Err(AtlasJsonError::Format);
+        };
+        let frames: Vec<&Value> = match frames {
+            Value::Array(arr) => arr.iter().collect(),
+            // Aseprite's hash export.
+            Value::Object(obj) => obj.values().collect(),
+            _ => return Err(AtlasJsonError::Format),
+        };
+        let mut rects = Vec::new();
+        for (i, frame) in frames.into_iter().enumerate() {
+            match as_rect(frame.get("frame").unwrap_or(frame)) {
+                Some(rect) => rects.push(rect),
+                None => warn!("Atlas frame {} is malformed, skipped.", i),
+            }
+        }
+        let size = size.unwrap_or_else(|| rects.iter().fold(Vec2::ZERO, |a, b| a.max(b.max)));
+        let mut atlas = TextureAtlasLayout::new_empty(size);
+        atlas.textures = rects;
+        Ok(atlas)
+    }
+
+    impl AssetLoader for AtlasJsonLoader {
+        type Asset = TextureAtlasLayout;
+        type Settings = ();
+        type Error = AtlasJsonError;
+
+        fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader,
+            _: &'a Self::Settings,
+            _: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+            Box::pin(async {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes).await?;
+                let value: Value = serde_json::from_slice(&bytes)?;
+                parse_atlas(&value)
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["atlas.json"]
+        }
+    }
+}
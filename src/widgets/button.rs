@@ -1,9 +1,19 @@
 use crate::dsl::prelude::Signals;
-use crate::events::{CursorAction, EventFlags};
+use crate::anim::{Attr, Offset};
+use crate::events::{CursorAction, CursorFocus, EventFlags, InputBlock, StrongFocusStateMachine};
+use crate::widgets::inputbox::InputBox;
+use crate::widgets::util::HoverExpand;
+use bevy::input::{keyboard::KeyCode, ButtonInput};
+use bevy::ecs::world::EntityWorldMut;
+use bevy::ecs::query::{Or, Without};
+use crate::{Opacity, Transform2D};
+use bevy::hierarchy::{Children, Parent};
+use bevy::math::Vec2;
 use bevy_defer::{Object, AsObject};
 use bevy_defer::signals::{Signal, SignalId, SignalSender, TypedSignal};
 use crate::util::CloneSplit;
-use bevy::ecs::system::{Commands, Query};
+use bevy::ecs::system::{Commands, Query, Res};
+use bevy::time::{Real, Time};
 use bevy::ecs::{component::Component, query::With};
 use bevy::reflect::std_traits::ReflectDefault;
 use bevy::{
@@ -11,6 +21,7 @@ use bevy::{
     reflect::Reflect,
 };
 use std::sync::Arc;
+use std::time::Duration;
 use parking_lot::Mutex;
 
 /// Marker for sending the `Submit` signal on click.
@@ -113,6 +124,16 @@ impl RadioButton {
         self.storage.lock().get()
     }
 
+    /// Obtain the current selection, `Object::NONE` if nothing is selected.
+    pub fn get_object(&self) -> Object {
+        self.storage.lock().clone()
+    }
+
+    /// Returns true if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.storage.lock().is_none()
+    }
+
     pub fn recv<T: AsObject>(&self) -> TypedSignal<T> {
         TypedSignal::from_signal(&self.sender)
     }
@@ -138,6 +159,17 @@ impl SignalId for ButtonClick {
     type Data = Object;
 }
 
+/// Signal sent by a `radio_button` when it changes the selection of its group.
+///
+/// Sends the new `Payload`, or `Object::NONE` if the selection
+/// is cleared by clicking a [`RadioButtonCancel`] button again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub struct RadioButtonChange;
+
+impl SignalId for RadioButtonChange {
+    type Data = Object;
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 pub struct ToggleChange;
 
@@ -158,13 +190,115 @@ impl SignalId for ToggleInvoke {
 #[derive(Debug, Clone, Copy, Component, PartialEq, Eq, Default, Reflect)]
 pub struct RadioButtonCancel;
 
+/// If this `radio_button` is selected while disabled by `Opacity`,
+/// move the selection to the next enabled sibling sharing its context.
+///
+/// Siblings are searched in order, wrapping around. If none is enabled, the selection is kept.
+#[derive(Debug, Clone, Copy, Component, PartialEq, Eq, Default, Reflect)]
+pub struct RadioButtonReselect;
+
+/// Suppresses repeated `ButtonClick` signals within a time window.
+///
+/// Useful for preventing double submits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub struct EventCooldown {
+    /// Duration after a click during which further clicks are ignored.
+    pub cooldown: Duration,
+    last: Option<Duration>,
+}
+
+impl EventCooldown {
+    pub const fn new(cooldown: Duration) -> Self {
+        Self { cooldown, last: None }
+    }
+
+    /// Returns true and resets the cooldown if not cooling down.
+    pub fn try_fire(&mut self, now: Duration) -> bool {
+        match self.last {
+            Some(last) if now.saturating_sub(last) < self.cooldown => false,
+            _ => {
+                self.last = Some(now);
+                true
+            }
+        }
+    }
+}
+
+impl From<Duration> for EventCooldown {
+    fn from(value: Duration) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Repeatedly sends `ButtonClick` while a `button` is held down, for spinner arrows.
+///
+/// Sends on press instead of on click, then again after `delay`, then every `interval`.
+/// Stops immediately when released or when the cursor leaves the hitbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub struct RepeatClick {
+    /// Duration before repeating starts.
+    pub delay: Duration,
+    /// Duration between repeated clicks.
+    pub interval: Duration,
+    next: Option<Duration>,
+}
+
+impl RepeatClick {
+    pub const fn new(delay: Duration, interval: Duration) -> Self {
+        Self { delay, interval, next: None }
+    }
+}
+
+impl Default for RepeatClick {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(400), Duration::from_millis(80))
+    }
+}
+
+/// Move this entity by an offset while the cursor is pressed on it,
+/// and back to its original position on release.
+///
+/// Drives `Interpolate<Offset>` if present, sets the offset directly otherwise.
+///
+/// This owns the entity's offset, to avoid fighting user-set offsets,
+/// add this to a child with no offset of a `button`, which receives the
+/// button's `CursorFocus` through [`PropagateFocus`](super::util::PropagateFocus).
+#[derive(Debug, Clone, Copy, PartialEq, Default, Component, Reflect)]
+pub struct PressOffset(pub Vec2);
+
+pub(crate) fn press_offset(
+    mut query: Query<(&PressOffset, Option<&CursorFocus>, Attr<Transform2D, Offset>)>,
+) {
+    for (press, focus, mut offset) in query.iter_mut() {
+        let target = match focus {
+            Some(focus) if focus.intersects(EventFlags::LeftPressed) => press.0,
+            _ => Vec2::ZERO,
+        };
+        let current = match &offset.interpolate {
+            Some(interpolate) => interpolate.target(),
+            None => offset.get(),
+        };
+        if current != target {
+            offset.set(target);
+        }
+    }
+}
+
 pub(crate) fn button_on_click(
-    query: Query<(&CursorAction, SignalSender<ButtonClick>, Option<&Payload>), With<Button>>,
+    time: Res<Time<Real>>,
+    mut query: Query<(&CursorAction, SignalSender<ButtonClick>, Option<&Payload>, Option<&mut EventCooldown>),
+        (With<Button>, Without<RepeatClick>)>,
 ) {
-    for (action, submit, payload) in query.iter() {
+    let now = time.elapsed();
+    for (action, submit, payload, cooldown) in query.iter_mut() {
         if !action.is(EventFlags::LeftClick) {
             continue;
         }
+        if let Some(mut cooldown) = cooldown {
+            if !cooldown.try_fire(now) {
+                continue;
+            }
+        }
         if let Some(payload) = payload {
             submit.send(payload.0.clone());
         } else {
@@ -173,6 +307,64 @@ pub(crate) fn button_on_click(
     }
 }
 
+pub(crate) fn repeat_click(
+    time: Res<Time<Real>>,
+    mut query: Query<(&mut RepeatClick, Option<&CursorAction>, Option<&CursorFocus>, SignalSender<ButtonClick>, Option<&Payload>), With<Button>>,
+) {
+    let now = time.elapsed();
+    for (mut repeat, action, focus, submit, payload) in query.iter_mut() {
+        let send = || match payload {
+            Some(payload) => submit.send(payload.0.clone()),
+            None => submit.send(Object::new(())),
+        };
+        if action.is_some_and(|x| x.is(EventFlags::LeftDown)) {
+            send();
+            repeat.next = Some(now + repeat.delay);
+            continue;
+        }
+        let pressed = focus.is_some_and(|x| x.intersects(EventFlags::LeftPressed));
+        match repeat.next {
+            Some(_) if !pressed => repeat.next = None,
+            Some(next) if now >= next => {
+                send();
+                repeat.next = Some(now + repeat.interval);
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Click the button with strong focus when `Enter` or `Space` is pressed.
+///
+/// Buttons need a [`StrongFocusStateMachine`] and `EventFlags::ClickOutside` to obtain
+/// strong focus, which is kept after clicking until clicking outside.
+///
+/// Does nothing if an [`InputBox`] has focus, since it consumes these keys.
+pub(crate) fn button_on_key(
+    mut commands: Commands,
+    block: Res<InputBlock>,
+    keys: Res<ButtonInput<KeyCode>>,
+    input_box: Query<&InputBox>,
+    query: Query<(Entity, &StrongFocusStateMachine), Or<(With<Button>, With<CheckButton>, With<RadioButton>)>>,
+) {
+    if block.is_blocked() || !keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space]) {
+        return;
+    }
+    if input_box.iter().any(|x| x.has_focus()) {
+        return;
+    }
+    for (entity, focus) in query.iter() {
+        if focus != &StrongFocusStateMachine::Strong {
+            continue;
+        }
+        commands.entity(entity).add(|mut entity: EntityWorldMut| {
+            if !entity.contains::<CursorAction>() {
+                entity.insert(CursorAction::new(EventFlags::LeftClick));
+            }
+        });
+    }
+}
+
 pub(crate) fn check_button_on_click(
     mut query: Query<(Option<&CursorAction>, &mut CheckButton, Option<&mut Signals>, Option<&Payload>)>,
 ) {
@@ -201,21 +393,50 @@ pub(crate) fn check_button_on_click(
 
 pub(crate) fn radio_button_on_click(
     mut query: Query<(
-        &CursorAction, &RadioButton, &Payload, SignalSender<ButtonClick>, Has<RadioButtonCancel>,
+        &CursorAction, &RadioButton, &Payload, &Opacity, SignalSender<ButtonClick>,
+        SignalSender<RadioButtonChange>, Has<RadioButtonCancel>,
     )>,
 ) {
-    for (action, state, payload, submit, cancellable) in query.iter_mut() {
-        if !action.is(EventFlags::LeftClick) {
+    for (action, state, payload, opacity, submit, change, cancellable) in query.iter_mut() {
+        if !action.is(EventFlags::LeftClick) || opacity.disabled || opacity.is_disabled() {
             continue;
         }
         if state == payload {
             if cancellable {
                 state.clear();
+                change.send(Object::NONE);
             }
             continue;
         }
         state.set(payload);
         submit.send(payload.0.clone());
+        change.send(payload.0.clone());
+    }
+}
+
+pub(crate) fn radio_button_reselect(
+    reselect: Query<(Entity, &RadioButton, &Payload, &Opacity, Option<&Parent>), With<RadioButtonReselect>>,
+    children: Query<&Children>,
+    members: Query<(&RadioButton, &Opacity)>,
+    mut query: Query<(&RadioButton, &Payload, SignalSender<RadioButtonChange>)>,
+) {
+    let is_disabled = |opacity: &Opacity| opacity.disabled || opacity.is_disabled();
+    for (entity, state, payload, opacity, parent) in reselect.iter() {
+        if !is_disabled(opacity) || state != payload {
+            continue;
+        }
+        let Some(siblings) = parent.and_then(|p| children.get(p.get()).ok()) else {continue};
+        let Some(index) = siblings.iter().position(|x| *x == entity) else {continue};
+        let next = siblings[index + 1..].iter().chain(siblings[..index].iter()).find(|sibling| {
+            match members.get(**sibling) {
+                Ok((other, opacity)) => Arc::ptr_eq(&state.storage, &other.storage) && !is_disabled(opacity),
+                Err(_) => false,
+            }
+        });
+        let Some(next) = next else {continue};
+        let Ok((other, payload, change)) = query.get_mut(*next) else {continue};
+        other.set(payload);
+        change.send(payload.get());
     }
 }
 
@@ -223,6 +444,7 @@ pub(crate) fn generate_check_button_state(
     mut commands: Commands,
     query1: Query<(Entity, &CheckButton)>,
     query2: Query<(Entity, &RadioButton, &Payload)>,
+    query3: Query<(Entity, &HoverExpand)>,
 ) {
     for (entity, btn) in query1.iter() {
         commands
@@ -234,6 +456,11 @@ pub(crate) fn generate_check_button_state(
             .entity(entity)
             .insert(CheckButtonState::from(radio == payload));
     }
+    for (entity, expand) in query3.iter() {
+        commands
+            .entity(entity)
+            .insert(CheckButtonState::from(expand.is_expanded()));
+    }
 }
 
 /// A dynamic piece of data.
@@ -1,15 +1,17 @@
-use crate::{Anchor, BuildTransform, DimensionData};
+use crate::{Anchor, BuildTransform, DimensionData, DisplayOpacity, Opacity};
 use bevy::asset::Handle;
 use bevy::core_pipeline::core_2d::Camera2dBundle;
 use bevy::core_pipeline::{
     core_2d::Camera2d,
     tonemapping::{DebandDither, Tonemapping},
 };
-use bevy::ecs::{bundle::Bundle, component::Component, query::With, system::Query};
+use bevy::ecs::{bundle::Bundle, component::Component, entity::Entity, query::{Has, With}, system::{Commands, Query}};
+use bevy::hierarchy::{Children, HierarchyQueryExt, Parent};
 use bevy::render::camera::{
     Camera, CameraMainTextureUsages, CameraRenderGraph, OrthographicProjection, RenderTarget, ScalingMode
 };
 use bevy::render::view::{RenderLayers, VisibleEntities};
+use bevy::utils::HashSet;
 use bevy::{
     render::{primitives::Frustum, texture::Image},
     transform::components::GlobalTransform,
@@ -22,6 +24,9 @@ use crate::util::convert::DslInto;
 pub struct CameraClip;
 
 /// A bundle that spawns a camera that draws its enclosed rectangle to a render target .
+///
+/// Insert a [`CameraDisplay`] to fade the sprite displaying the render target
+/// with the `Opacity` of this entity.
 #[derive(Bundle)]
 #[non_exhaustive]
 pub struct ScopedCameraBundle {
@@ -75,3 +80,85 @@ pub(crate) fn sync_camera_dimension(
         };
     }
 }
+
+/// The entity displaying the render target of a [`CameraClip`].
+///
+/// The display fades with the `Opacity` of the camera frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct CameraDisplay(pub Entity);
+
+/// Fade the [`CameraDisplay`] of a [`CameraClip`] with the camera frame.
+///
+/// Writes the camera frame's opacity relative to their common ancestor to [`DisplayOpacity`],
+/// since the common ancestor's opacity is already applied to the display.
+/// The render target is not reallocated.
+pub(crate) fn sync_camera_opacity(
+    mut commands: Commands,
+    cameras: Query<(Entity, &CameraDisplay, &Opacity), With<CameraClip>>,
+    opacity: Query<&Opacity>,
+    parents: Query<&Parent>,
+    mut displays: Query<Option<&mut DisplayOpacity>>,
+) {
+    for (camera, display, camera_opacity) in cameras.iter() {
+        let Ok(current) = displays.get_mut(display.0) else {continue};
+        let ancestors: HashSet<Entity> = std::iter::once(display.0)
+            .chain(parents.iter_ancestors(display.0))
+            .collect();
+        let common = std::iter::once(camera)
+            .chain(parents.iter_ancestors(camera))
+            .find(|entity| ancestors.contains(entity))
+            .and_then(|entity| opacity.get(entity).ok())
+            .map(|x| x.computed_opacity)
+            .filter(|x| *x > 0.0)
+            .unwrap_or(1.0);
+        let value = camera_opacity.computed_opacity / common;
+        match current {
+            Some(mut current) => if current.0 != value {
+                current.0 = value;
+            },
+            None => { commands.entity(display.0).insert(DisplayOpacity(value)); },
+        }
+    }
+}
+
+/// Propagate this entity's [`RenderLayers`] to all its descendants.
+///
+/// Descendants with their own `RenderLayers` override the propagated value
+/// and stop propagation for their subtree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+pub struct PropagateRenderLayers;
+
+/// Marker for [`RenderLayers`] inserted by [`PropagateRenderLayers`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+pub struct InheritedRenderLayers;
+
+pub(crate) fn propagate_render_layers(
+    mut commands: Commands,
+    roots: Query<(&RenderLayers, &Children), With<PropagateRenderLayers>>,
+    children: Query<&Children>,
+    layers: Query<(Option<&RenderLayers>, Has<InheritedRenderLayers>)>,
+) {
+    fn propagate(
+        commands: &mut Commands,
+        layer: RenderLayers,
+        entity: Entity,
+        children: &Query<&Children>,
+        layers: &Query<(Option<&RenderLayers>, Has<InheritedRenderLayers>)>,
+    ) {
+        let Ok((current, inherited)) = layers.get(entity) else {return};
+        match current {
+            Some(_) if !inherited => return,
+            Some(current) if current == &layer => (),
+            _ => { commands.entity(entity).insert((layer, InheritedRenderLayers)); },
+        }
+        let Ok(grand_children) = children.get(entity) else {return};
+        for child in grand_children.iter() {
+            propagate(commands, layer, *child, children, layers);
+        }
+    }
+    for (layer, root_children) in roots.iter() {
+        for child in root_children.iter() {
+            propagate(&mut commands, *layer, *child, &children, &layers);
+        }
+    }
+}
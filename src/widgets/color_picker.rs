@@ -0,0 +1,150 @@
+use bevy::ecs::{change_detection::DetectChanges, component::Component, entity::Entity, query::With, system::{Commands, Query}};
+use bevy::hierarchy::{Children, Parent};
+use bevy::math::Vec2;
+use bevy::reflect::Reflect;
+use bevy::render::color::Color;
+use bevy_defer::signal_ids;
+use bevy_defer::signals::{SignalId, SignalReceiver, SignalSender};
+
+use crate::{Anchor, Coloring, DimensionData, Transform2D};
+use crate::anim::{Attr, Offset};
+use crate::util::Rem;
+
+signal_ids!(
+    /// Receives the hue of a [`ColorPicker`] in `0..=1`.
+    pub ColorPickerHue: f32,
+    /// Receives the saturation and value of a [`ColorPicker`] in `0..=1`.
+    pub ColorPickerSV: Vec2,
+    /// Receives the alpha of a [`ColorPicker`] in `0..=1`.
+    pub ColorPickerAlpha: f32,
+);
+
+/// Context of a `color_picker`, stored in `HSVA`.
+///
+/// When used as a signal id, sends the picked [`Color`] on change.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ColorPicker {
+    /// Hue in degrees.
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+    pub alpha: f32,
+}
+
+impl SignalId for ColorPicker {
+    type Data = Color;
+}
+
+impl Default for ColorPicker {
+    fn default() -> Self {
+        Self { hue: 0.0, saturation: 0.0, value: 0.0, alpha: 1.0 }
+    }
+}
+
+impl ColorPicker {
+    pub const fn new(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        Self { hue, saturation, value, alpha }
+    }
+
+    /// Obtain the picked color.
+    pub fn color(&self) -> Color {
+        hsva(self.hue, self.saturation, self.value, self.alpha)
+    }
+
+    /// Obtain the fully saturated color of the current hue.
+    pub fn hue_color(&self) -> Color {
+        hsva(self.hue, 1.0, 1.0, 1.0)
+    }
+}
+
+fn hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Color {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let c = value * saturation;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = value - c;
+    Color::rgba(r + m, g + m, b + m, alpha)
+}
+
+/// Marker for a child of [`ColorPicker`] whose `Coloring` is set to the current hue,
+/// usually the saturation/value square.
+#[derive(Debug, Clone, Copy, Default, Component, Reflect)]
+pub struct ColorPickerHueDisplay;
+
+pub(crate) fn color_picker_system(
+    mut query: Query<(
+        &mut ColorPicker,
+        SignalReceiver<ColorPickerHue>,
+        SignalReceiver<ColorPickerSV>,
+        SignalReceiver<ColorPickerAlpha>,
+        SignalSender<ColorPicker>,
+        Option<&Children>,
+    )>,
+    mut display: Query<&mut Coloring, With<ColorPickerHueDisplay>>,
+) {
+    for (mut picker, hue, sv, alpha, send, children) in query.iter_mut() {
+        if let Some(hue) = hue.poll_once() {
+            picker.hue = hue.clamp(0.0, 1.0) * 360.0;
+        }
+        if let Some(sv) = sv.poll_once() {
+            picker.saturation = sv.x.clamp(0.0, 1.0);
+            picker.value = sv.y.clamp(0.0, 1.0);
+        }
+        if let Some(alpha) = alpha.poll_once() {
+            picker.alpha = alpha.clamp(0.0, 1.0);
+        }
+        if !picker.is_changed() {
+            continue;
+        }
+        send.send(picker.color());
+        let Some(children) = children else {continue};
+        let hue_color = picker.hue_color();
+        let mut iter = display.iter_many_mut(children);
+        while let Some(mut coloring) = iter.fetch_next() {
+            coloring.color = hue_color;
+        }
+    }
+}
+
+/// Initial position of a `color_picker` handle as a fraction of the range it can be dragged in.
+///
+/// Removed once applied, which requires the dimension of the handle and its parent.
+#[derive(Debug, Clone, Copy, Component)]
+pub(crate) struct ColorPickerHandleInit(pub Vec2);
+
+pub(crate) fn color_picker_handle_init(
+    mut commands: Commands,
+    rem: Rem,
+    mut query: Query<(Entity, &ColorPickerHandleInit, &Parent, &DimensionData, Attr<Transform2D, Offset>)>,
+    parent_query: Query<&DimensionData>,
+) {
+    let rem = rem.get();
+    for (entity, init, parent, dim, mut transform) in query.iter_mut() {
+        let Ok(dimension) = parent_query.get(parent.get()).map(|x| x.size) else {continue};
+        if dimension.cmple(Vec2::ZERO).any() || dim.size.cmple(Vec2::ZERO).any() {
+            continue;
+        }
+        // Same range as `constraint_system`.
+        let origin = dimension * transform.component.get_parent_anchor()
+            - dim.size * transform.component.anchor;
+        let min = dimension * Anchor::BOTTOM_LEFT + dim.size / 2.0 - origin;
+        let max = dimension * Anchor::TOP_RIGHT - dim.size / 2.0 - origin;
+        let (min, max) = (min.min(max), min.max(max));
+        let mut pos = transform.get_pixels(dimension, dim.em, rem);
+        if init.0.x.is_finite() {
+            pos.x = (max.x - min.x) * init.0.x + min.x;
+        }
+        if init.0.y.is_finite() {
+            pos.y = (max.y - min.y) * init.0.y + min.y;
+        }
+        transform.force_set(pos);
+        commands.entity(entity).remove::<ColorPickerHandleInit>();
+    }
+}
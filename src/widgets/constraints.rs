@@ -1,12 +1,14 @@
 use bevy::ecs::bundle::Bundle;
 use bevy::ecs::component::Component;
+use bevy::ecs::query::Changed;
+use bevy::ecs::system::Query;
 use bevy::ecs::query::WorldQuery;
 use bevy::log::warn;
 use bevy::math::Vec2;
 use bevy::reflect::Reflect;
 use crate::dsl::prelude::Signals;
 use crate::util::convert::DslConvert;
-use crate::DimensionData;
+use crate::{Dimension, DimensionData};
 use bevy_defer::signals::SignalId;
 use crate::{Transform2D, Anchor, anim::Attr};
 use crate::anim::Offset;
@@ -51,6 +53,16 @@ impl SignalId for PositionFac {
     type Data = f32;
 }
 
+/// A signal that sends a value in `0..=1` on both axes in its constraints when being scrolled.
+///
+/// An axis that cannot be scrolled or has no overflow reports `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollFraction {}
+
+impl SignalId for ScrollFraction {
+    type Data = Vec2;
+}
+
 /// A shared percentage based position.
 #[derive(Debug, Default, Clone, Component, Reflect)]
 pub struct SharedPosition{
@@ -79,6 +91,57 @@ impl SignalId for SharedPosition {
 #[derive(Debug, Clone, Copy, Component, PartialEq, Eq, Default, Reflect)]
 pub struct Constraint;
 
+/// Keeps the aspect ratio (`width / height`) of an owned [`Dimension`] during resizing.
+///
+/// Width is treated as the source of truth, height is derived from it.
+/// If the result is smaller than `min` on either axis,
+/// both axes are scaled up so the ratio is still respected.
+///
+/// Operates on the raw value of the dimension, units of both axes should match.
+#[derive(Debug, Clone, Copy, Component, PartialEq, Reflect)]
+pub struct AspectConstraint {
+    pub ratio: f32,
+    pub min: Vec2,
+}
+
+impl AspectConstraint {
+    pub const fn new(ratio: f32) -> Self {
+        Self { ratio, min: Vec2::ZERO }
+    }
+
+    pub const fn with_min(mut self, min: Vec2) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Apply the constraint to a size, keeping its width if possible.
+    pub fn apply(&self, size: Vec2) -> Vec2 {
+        if !(self.ratio > 0.0 && self.ratio.is_finite()) {
+            return size.max(self.min);
+        }
+        let width = size.x
+            .max(self.min.x)
+            .max(self.min.y * self.ratio);
+        Vec2::new(width, width / self.ratio)
+    }
+}
+
+pub(crate) fn aspect_constraint_system(
+    mut query: Query<(&AspectConstraint, &mut Dimension), Changed<Dimension>>,
+) {
+    for (constraint, mut dimension) in query.iter_mut() {
+        if !dimension.is_owned() {
+            continue;
+        }
+        let mut raw = Vec2::ZERO;
+        dimension.with_raw(|v| raw = v);
+        let value = constraint.apply(raw);
+        if value != raw {
+            dimension.edit_raw(|v| *v = value);
+        }
+    }
+}
+
 pub(crate) type ConstraintQuery = (
     &'static DimensionData,
     Option<&'static SharedPosition>,
@@ -120,6 +183,10 @@ pub fn constraint_system(
     let Some(signals) = signals else {return};
     // broadcast bypasses poll_senders_once.
     signals.broadcast::<SharedPosition>(flip_vec(fac, flip));
+    signals.send::<ScrollFraction>(Vec2::new(
+        if dir_x && max.x > min.x {fac.x.clamp(0.0, 1.0)} else {0.0},
+        if dir_y && max.y > min.y {fac.y.clamp(0.0, 1.0)} else {0.0},
+    ));
     match (dir_x, dir_y) {
         (true, false) => {
             let value = fac.x.clamp(0.0, 1.0);
@@ -1,23 +1,28 @@
 use bevy::ecs::bundle::Bundle;
-use bevy::ecs::query::{Has, With};
-use bevy::hierarchy::Parent;
+use bevy::ecs::query::{Changed, Has, With};
+use bevy::hierarchy::{Children, Parent};
 use bevy::math::Vec2;
 use bevy::ecs::{component::Component, query::Without, entity::Entity};
 use bevy::ecs::system::{Query, Res};
 use bevy_defer::signals::{SignalId, SignalReceiver, SignalSender};
+use bevy::log::warn;
 use crate::util::{Rem, WindowSize};
-use crate::DimensionData;
+use crate::{Anchor, Dimension, DimensionData, DimensionType, RotatedRect};
 use crate::{Transform2D, anim::Attr};
 use serde::{Serialize, Deserialize};
 
 use crate::{events::{CursorAction, CursorState, EventFlags, CursorFocus}, anim::Offset};
 
 use super::constraints::{constraint_system, listen_shared_position, Constraint, ConstraintBundle, ConstraintQuery};
-use super::constraints::SharedPosition;
+use super::constraints::{AspectConstraint, SharedPosition};
 
 /// A component that enables dragging and dropping.
 /// By default the sprite can be dragged anywhere with no restriction.
 ///
+/// The point grabbed stays under the cursor during the drag,
+/// including under rotated or scaled parents.
+/// If constrained, the position is clamped after following the cursor.
+///
 /// This works with all mouse buttons as long as
 /// you add the corresponding `EventFlags`.
 ///
@@ -26,6 +31,7 @@ use super::constraints::SharedPosition;
 /// * [`EventFlags`]: Requires `Drag` to be set.
 /// * [`Constraint`]: If specified, the sprite cannot go over bounds of its parent.
 /// * [`DragSnapBack`]: Move the sprite back to its original position when dropped.
+/// * [`DragProxy`]: Drag this sprite from a handle entity.
 /// * [`Dragging`]: When used as a signal, 
 ///     receives `MouseDrag` on a draggable sprite with no event listener.
 ///     This is useful for creating a small draggable area, like a banner.
@@ -105,6 +111,85 @@ impl Default for Dragging {
     }
 }
 
+/// Dragging this sprite drags the target's [`Dragging`] instead, i.e. a title bar of a window.
+///
+/// Requires `EventFlags::LeftDrag` or equivalent on this sprite.
+/// The target's [`Constraint`] and [`DragSnapBack`] apply to the target, not the handle.
+#[derive(Debug, Clone, Copy, Component, PartialEq)]
+pub struct DragProxy(pub Entity);
+
+/// Bring the sprite in front of its siblings when a drag starts by bumping `z`.
+#[derive(Debug, Clone, Copy, Component, PartialEq)]
+pub struct RaiseOnDrag(pub f32);
+
+impl Default for RaiseOnDrag {
+    fn default() -> Self {
+        Self(0.01)
+    }
+}
+
+/// Resize a target's owned [`Dimension`] when dragged.
+///
+/// Requires `EventFlags::LeftDrag` or equivalent. The target's dimension must be in pixels.
+/// If the target has an [`AspectConstraint`], it is applied after `min` and `max`.
+///
+/// The handle should be a child of the target anchored to the side it resizes,
+/// so it follows the target as it resizes. To keep the opposite side fixed,
+/// anchor the target to the opposite side of the handle.
+#[derive(Debug, Clone, Copy, Component, PartialEq)]
+pub struct ResizeHandle {
+    /// Entity to resize, if `None`, resizes the parent.
+    pub target: Option<Entity>,
+    /// Direction the target grows in when dragged, each component is `-1`, `0` or `1`.
+    pub direction: Vec2,
+    /// Minimum size of the target.
+    pub min: Vec2,
+    /// Maximum size of the target.
+    pub max: Vec2,
+    size_start: Option<Vec2>,
+}
+
+impl ResizeHandle {
+    /// Create a handle on a side or corner of the target.
+    ///
+    /// `Anchor::CENTER_RIGHT` creates a handle that resizes width only,
+    /// `Anchor::TOP_RIGHT` resizes both width and height.
+    pub fn new(anchor: Anchor) -> Self {
+        let sign = |v: f32| if v > 0.0 {1.0} else if v < 0.0 {-1.0} else {0.0};
+        let anchor = anchor.as_vec();
+        Self {
+            target: None,
+            direction: Vec2::new(sign(anchor.x), sign(anchor.y)),
+            min: Vec2::ZERO,
+            max: Vec2::INFINITY,
+            size_start: None,
+        }
+    }
+
+    /// Resize a specific entity instead of the parent.
+    pub fn with_target(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Set the minimum and maximum size of the target.
+    pub fn with_bounds(mut self, min: Vec2, max: Vec2) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    fn resolve(&self, parent: Option<&Parent>) -> Option<Entity> {
+        self.target.or_else(|| parent.map(|x| x.get()))
+    }
+}
+
+impl Default for ResizeHandle {
+    fn default() -> Self {
+        Self::new(Anchor::BOTTOM_RIGHT)
+    }
+}
+
 /// Component that moves the sprite back to its original position if dropped.
 #[derive(Debug, Clone, Copy, Component, Default)]
 pub struct DragSnapBack {
@@ -122,21 +207,28 @@ impl DragSnapBack {
 
 pub(crate) fn drag_start(
     send: Query<(&CursorAction, SignalSender<Dragging>), Without<Dragging>>,
-    mut receive: Query<(SignalReceiver<Dragging>, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>), Without<CursorAction>>,
-    mut query: Query<(&CursorAction, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>)>,
+    proxy: Query<(&CursorAction, &DragProxy)>,
+    mut receive: Query<(Entity, SignalReceiver<Dragging>, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>), Without<CursorAction>>,
+    mut query: Query<(Entity, &CursorAction, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>)>,
 ) {
     for (focus, send) in send.iter() {
         if focus.intersects(EventFlags::AnyDown)  {
             send.send(DragState::Start);
         }
     }
+    let proxied: Vec<_> = proxy.iter()
+        .filter(|(action, _)| action.intersects(EventFlags::AnyDown))
+        .map(|(_, proxy)| proxy.0)
+        .collect();
 
     let iter = query.iter_mut()
-        .filter_map(|(action, drag, transform, snap)| {
-            action.intersects(EventFlags::AnyDown).then_some((drag, transform, snap))
+        .filter_map(|(entity, action, drag, transform, snap)| {
+            (action.intersects(EventFlags::AnyDown) || proxied.contains(&entity))
+                .then_some((drag, transform, snap))
         }).chain(receive.iter_mut()
-        .filter_map(|(action, drag, transform, snap)|{
-            (action.poll_once() == Some(DragState::Start)).then_some((drag, transform, snap))
+        .filter_map(|(entity, action, drag, transform, snap)|{
+            (action.poll_once() == Some(DragState::Start) || proxied.contains(&entity))
+                .then_some((drag, transform, snap))
         }));
 
     for (mut drag, mut transform, mut snap) in iter {
@@ -167,26 +259,33 @@ pub(crate) fn dragging(
     rem: Rem,
     state: Res<CursorState>,
     send: Query<(&CursorFocus, SignalSender<Dragging>), Without<Dragging>>,
+    proxy: Query<(&CursorFocus, &DragProxy)>,
     mut query: Query<(
         Entity, Option<&Parent>, &Dragging, Attr<Transform2D, Offset>, 
         Option<&CursorFocus>, SignalReceiver<Dragging>, Has<SharedPosition>,
     )>,
     mut constraints: Query<ConstraintQuery, With<Constraint>>,
     parent_query: Query<&DimensionData>,
+    parent_rect: Query<&RotatedRect>,
 ) {
     let window_size = window_size.get();
     let rem = rem.get();
-    let delta = state.cursor_position() - state.down_position();
+    let world_delta = state.cursor_position() - state.down_position();
     for (focus, send) in send.iter() {
         if !focus.intersects(EventFlags::AnyDrag)  {
             continue;
         }
         send.send(DragState::Dragging);
     }
+    let proxied: Vec<_> = proxy.iter()
+        .filter(|(focus, _)| focus.intersects(EventFlags::AnyDrag))
+        .map(|(_, proxy)| proxy.0)
+        .collect();
     for (entity, parent, drag, mut transform, focus, recv, has_shared) in query.iter_mut() {
         if !(drag.x || drag.y) { continue; }
         if !focus.map(|x| x.intersects(EventFlags::AnyDrag)).unwrap_or(false) 
-                && recv.poll_once() != Some(DragState::Dragging) {
+                && recv.poll_once() != Some(DragState::Dragging)
+                && !proxied.contains(&entity) {
             if has_shared {
                 if let Ok(constraints) = constraints.get_mut(entity) {
                     let parent = parent
@@ -199,6 +298,12 @@ pub(crate) fn dragging(
             continue;
         }
 
+        // Offset is in the parent's local space, undo the parent's rotation and scale
+        // so the grabbed point stays under the cursor.
+        let delta = match parent.and_then(|x| parent_rect.get(x.get()).ok()) {
+            Some(rect) => Vec2::from_angle(-rect.rotation).rotate(world_delta) / rect.scale,
+            None => world_delta,
+        };
         let pos = drag.last_drag_start() + {
             Vec2::new(
                 if drag.x {delta.x} else {0.0},
@@ -220,8 +325,9 @@ pub(crate) fn dragging(
 
 pub(crate) fn drag_end(
     send: Query<(&CursorAction, SignalSender<Dragging>), Without<Dragging>>,
-    mut receive: Query<(&mut DragSnapBack, Attr<Transform2D, Offset>, SignalReceiver<Dragging>), Without<CursorAction>>,
-    mut query: Query<(&CursorAction, &mut DragSnapBack, Attr<Transform2D, Offset>)>
+    proxy: Query<(&CursorAction, &DragProxy)>,
+    mut receive: Query<(Entity, &mut DragSnapBack, Attr<Transform2D, Offset>, SignalReceiver<Dragging>), Without<CursorAction>>,
+    mut query: Query<(Entity, &CursorAction, &mut DragSnapBack, Attr<Transform2D, Offset>)>
 ) {
     for (focus, send) in send.iter() {
         if !focus.intersects(EventFlags::DragEnd)  {
@@ -229,17 +335,21 @@ pub(crate) fn drag_end(
         }
         send.send(DragState::End);
     }
+    let proxied: Vec<_> = proxy.iter()
+        .filter(|(action, _)| action.intersects(EventFlags::DragEnd))
+        .map(|(_, proxy)| proxy.0)
+        .collect();
 
     let iter = query.iter_mut()
-        .filter_map(|(action, drag, transform)| {
-            if action.intersects(EventFlags::DragEnd) {
+        .filter_map(|(entity, action, drag, transform)| {
+            if action.intersects(EventFlags::DragEnd) || proxied.contains(&entity) {
                 Some((drag, transform))
             } else {
                 None
             }
         }).chain(receive.iter_mut()
-        .filter_map(|(drag, transform, recv)|{
-            if recv.poll_once() == Some(DragState::End) {
+        .filter_map(|(entity, drag, transform, recv)|{
+            if recv.poll_once() == Some(DragState::End) || proxied.contains(&entity) {
                 Some((drag, transform))
             } else {
                 None
@@ -252,3 +362,70 @@ pub(crate) fn drag_end(
         }
     }
 }
+
+pub(crate) fn raise_on_drag(
+    query: Query<(Entity, &RaiseOnDrag, Option<&Parent>), Changed<Dragging>>,
+    children: Query<&Children>,
+    roots: Query<Entity, (With<Transform2D>, Without<Parent>)>,
+    mut transforms: Query<&mut Transform2D>,
+) {
+    for (entity, raise, parent) in query.iter() {
+        let max = match parent.and_then(|x| children.get(x.get()).ok()) {
+            Some(siblings) => siblings.iter()
+                .filter(|x| **x != entity)
+                .filter_map(|x| transforms.get(*x).ok())
+                .map(|x| x.z)
+                .fold(f32::MIN, f32::max),
+            None => roots.iter()
+                .filter(|x| *x != entity)
+                .filter_map(|x| transforms.get(x).ok())
+                .map(|x| x.z)
+                .fold(f32::MIN, f32::max),
+        };
+        let Ok(mut transform) = transforms.get_mut(entity) else {continue};
+        if max >= transform.z {
+            transform.z = max + raise.0;
+        }
+    }
+}
+
+pub(crate) fn resize_handle_start(
+    mut query: Query<(&CursorAction, &mut ResizeHandle, Option<&Parent>)>,
+    targets: Query<&Dimension>,
+) {
+    for (action, mut handle, parent) in query.iter_mut() {
+        if !action.intersects(EventFlags::AnyDown) {
+            continue;
+        }
+        let Some(target) = handle.resolve(parent) else {continue};
+        let Ok(dimension) = targets.get(target) else {continue};
+        handle.size_start = match dimension.dimension {
+            DimensionType::Owned(size) => size.get_pixels(),
+            _ => None,
+        };
+        if handle.size_start.is_none() {
+            warn!("Resize target {target:?} must have an owned dimension in pixels.");
+        }
+    }
+}
+
+pub(crate) fn resize_handle_dragging(
+    state: Res<CursorState>,
+    query: Query<(&CursorFocus, &ResizeHandle, Option<&Parent>)>,
+    mut targets: Query<(Attr<Dimension, Dimension>, Option<&AspectConstraint>)>,
+) {
+    let delta = state.cursor_position() - state.down_position();
+    for (focus, handle, parent) in query.iter() {
+        if !focus.intersects(EventFlags::AnyDrag) {
+            continue;
+        }
+        let Some(start) = handle.size_start else {continue};
+        let Some(target) = handle.resolve(parent) else {continue};
+        let Ok((mut dimension, aspect)) = targets.get_mut(target) else {continue};
+        let mut size = (start + delta * handle.direction).clamp(handle.min, handle.max.max(handle.min));
+        if let Some(aspect) = aspect {
+            size = aspect.apply(size);
+        }
+        dimension.force_set(size);
+    }
+}
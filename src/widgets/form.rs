@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use bevy::ecs::{component::Component, entity::Entity};
+use bevy::ecs::system::{Query, SystemParam};
+use bevy_defer::{signal_ids, Object};
+use bevy_defer::signals::{SignalReceiver, SignalSender};
+
+use super::button::{CheckButton, RadioButton};
+use super::inputbox::InputBox;
+
+type Validator = Arc<dyn Fn(&Object) -> Result<(), String> + Send + Sync>;
+
+signal_ids!(
+    /// Signal that triggers collection of a [`Form`].
+    pub FormSubmit: Object,
+    /// Signal that sends [`FormValues`] if validation succeeds.
+    pub FormOutput: Object,
+    /// Signal that sends [`FormErrors`] if validation fails.
+    pub FormReject: Object,
+);
+
+/// A named field in a [`Form`].
+#[derive(Clone)]
+pub struct FormField {
+    pub name: String,
+    pub entity: Entity,
+    validator: Option<Validator>,
+}
+
+impl Debug for FormField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormField")
+            .field("name", &self.name)
+            .field("entity", &self.entity)
+            .field("validated", &self.validator.is_some())
+            .finish()
+    }
+}
+
+/// Values collected from a [`Form`], keyed by field name.
+///
+/// * `InputBox` produces `String`.
+/// * `CheckButton` produces `bool`.
+/// * `RadioButton` produces its current value.
+#[derive(Debug, Clone, Default)]
+pub struct FormValues(pub HashMap<String, Object>);
+
+// `Object` is not `PartialEq`, required by `AsObject`.
+impl PartialEq for FormValues {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter()
+            .all(|(k, v)| other.0.get(k).is_some_and(|x| v.equal_to(x)))
+    }
+}
+
+/// Validation errors of a [`Form`], keyed by field name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormErrors(pub HashMap<String, String>);
+
+/// Aggregates values of named input widgets.
+///
+/// On receiving [`FormSubmit`], sends [`FormValues`] through [`FormOutput`]
+/// or [`FormErrors`] through [`FormReject`].
+#[derive(Debug, Clone, Default, Component)]
+pub struct Form {
+    fields: Vec<FormField>,
+}
+
+impl Form {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a named `InputBox`, `CheckButton` or `RadioButton`.
+    pub fn with_field(mut self, name: impl Into<String>, entity: Entity) -> Self {
+        self.register(name, entity);
+        self
+    }
+
+    /// Register a named field with a validator.
+    pub fn with_validated_field(
+        mut self,
+        name: impl Into<String>,
+        entity: Entity,
+        validator: impl Fn(&Object) -> Result<(), String> + Send + Sync + 'static
+    ) -> Self {
+        self.fields.push(FormField {
+            name: name.into(),
+            entity,
+            validator: Some(Arc::new(validator)),
+        });
+        self
+    }
+
+    /// Register a named `InputBox`, `CheckButton` or `RadioButton`.
+    pub fn register(&mut self, name: impl Into<String>, entity: Entity) {
+        self.fields.push(FormField {
+            name: name.into(),
+            entity,
+            validator: None,
+        });
+    }
+
+    pub fn fields(&self) -> &[FormField] {
+        &self.fields
+    }
+}
+
+/// Query for reading values of widgets registered in a [`Form`].
+#[derive(SystemParam)]
+pub struct FormQuery<'w, 's> {
+    input_box: Query<'w, 's, &'static InputBox>,
+    check_button: Query<'w, 's, &'static CheckButton>,
+    radio_button: Query<'w, 's, &'static RadioButton>,
+}
+
+impl FormQuery<'_, '_> {
+    /// Obtain the current value of a supported widget.
+    pub fn get(&self, entity: Entity) -> Option<Object> {
+        if let Ok(input) = self.input_box.get(entity) {
+            Some(Object::new(input.get().to_owned()))
+        } else if let Ok(check) = self.check_button.get(entity) {
+            Some(Object::new(check.get()))
+        } else if let Ok(radio) = self.radio_button.get(entity) {
+            Some(radio.storage.lock().clone())
+        } else {
+            None
+        }
+    }
+
+    /// Collect all values in a form, errors are aggregated per field.
+    pub fn collect(&self, form: &Form) -> Result<FormValues, FormErrors> {
+        let mut values = HashMap::new();
+        let mut errors = HashMap::new();
+        for field in &form.fields {
+            let Some(value) = self.get(field.entity) else {
+                errors.insert(field.name.clone(), "Field is not a supported widget.".to_owned());
+                continue;
+            };
+            if let Some(validator) = &field.validator {
+                if let Err(err) = validator(&value) {
+                    errors.insert(field.name.clone(), err);
+                    continue;
+                }
+            }
+            values.insert(field.name.clone(), value);
+        }
+        if errors.is_empty() {
+            Ok(FormValues(values))
+        } else {
+            Err(FormErrors(errors))
+        }
+    }
+}
+
+pub(crate) fn form_on_submit(
+    mut query: Query<(&Form, SignalReceiver<FormSubmit>, SignalSender<FormOutput>, SignalSender<FormReject>)>,
+    fields: FormQuery,
+) {
+    for (form, submit, output, reject) in query.iter_mut() {
+        if submit.poll_once().is_none() {
+            continue;
+        }
+        match fields.collect(form) {
+            Ok(values) => output.send(Object::new(values)),
+            Err(errors) => reject.send(Object::new(errors)),
+        }
+    }
+}
@@ -2,20 +2,22 @@ use std::mem;
 use crate::anim::VisibilityToggle;
 use crate::dimension::DimensionMut;
 use crate::events::{
-    ActiveDetection, CursorAction, CursorClickOutside, CursorFocus, CursorState,
-    EventFlags,
+    ActiveDetection, CursorAction, CursorClickOutside, CursorFocus, CursorState, InputBlock,
+    EventFlags, MouseWheelAction,
 };
 use bevy::input::ButtonInput;
 use bevy_defer::signals::{SignalId, SignalSender};
-use crate::{RotatedRect, Transform2D, DimensionData, Size, size, RectrayRem};
+use crate::{RotatedRect, Transform2D, DimensionData, Size, size, RectrayRem, SyncEm};
 use ab_glyph::{Font as FontTrait, ScaleFont};
 use bevy::asset::{Assets, Handle};
 use bevy::ecs::query::Or;
+use bevy::ecs::change_detection::{DetectChanges, DetectChangesMut};
 
-use bevy::ecs::{event::EventReader, query::Changed, system::Commands};
+use bevy::ecs::{event::EventReader, query::Changed, system::{Command, Commands}, world::World};
 use bevy::hierarchy::Children;
 use bevy::input::keyboard::KeyCode;
-use bevy::prelude::{Component, Entity, Query, Res, With, Without};
+use bevy::prelude::{Component, Entity, Mut, Query, Res, Resource, With, Without};
+use bevy::time::Time;
 use bevy::reflect::Reflect;
 
 use bevy::text::Font;
@@ -95,6 +97,129 @@ pub struct InputBox {
     active: LeftRight,
     max_len: Size,
     em: f32,
+    blink: f32,
+}
+
+type Preprocessor = Box<dyn FnMut(&str) -> String + Send + Sync>;
+
+/// Transforms or vetoes typed characters before they are applied to an [`InputBox`].
+///
+/// Receives each typed character as a string, returning an empty string drops it.
+/// Control characters like backspace and enter are not preprocessed.
+#[derive(Component)]
+pub struct InputPreprocessor(Preprocessor);
+
+impl std::fmt::Debug for InputPreprocessor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("InputPreprocessor").finish_non_exhaustive()
+    }
+}
+
+impl InputPreprocessor {
+    /// Create a preprocessor from a function, may hold state for things like dead keys.
+    pub fn new(f: impl FnMut(&str) -> String + Send + Sync + 'static) -> Self {
+        Self(Box::new(f))
+    }
+
+    /// Only accept characters satisfying a predicate.
+    pub fn filter(f: impl Fn(char) -> bool + Send + Sync + 'static) -> Self {
+        Self::new(move |s| s.chars().filter(|c| f(*c)).collect())
+    }
+
+    /// Only accept characters of a decimal number, digits, `-` and `.`.
+    pub fn numeric() -> Self {
+        Self::filter(|c| c.is_ascii_digit() || c == '-' || c == '.')
+    }
+
+    /// Convert all characters to uppercase.
+    pub fn uppercase() -> Self {
+        Self::new(|s| s.to_uppercase())
+    }
+
+    /// Apply the preprocessor to a string.
+    pub fn process(&mut self, s: &str) -> String {
+        (self.0)(s)
+    }
+}
+
+/// Allows the mouse wheel to increment or decrement a numeric [`InputBox`] when hovered.
+///
+/// Requires `EventFlags::MouseWheel`. Non-numeric text is left unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct InputStep {
+    /// Value added per line scrolled.
+    pub step: f32,
+    /// Minimum value.
+    pub min: f32,
+    /// Maximum value.
+    pub max: f32,
+    /// Number of decimal places displayed.
+    pub precision: usize,
+}
+
+impl InputStep {
+    pub const fn new(step: f32) -> Self {
+        Self {
+            step,
+            min: f32::MIN,
+            max: f32::MAX,
+            precision: 0,
+        }
+    }
+
+    pub const fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+}
+
+impl From<f32> for InputStep {
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Blink rate of the cursor bar of an [`InputBox`], in seconds.
+///
+/// Can be inserted as a resource or as a component on an [`InputBox`],
+/// the component takes precedence. If neither is present, the cursor does not blink.
+///
+/// The cursor stays solid while typing or moving and restarts its cycle afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Resource, Reflect)]
+pub struct CursorBlink {
+    /// Duration the cursor is visible.
+    pub on: f32,
+    /// Duration the cursor is hidden, `0.0` disables blinking.
+    pub off: f32,
+}
+
+impl CursorBlink {
+    /// A cursor that does not blink.
+    pub const SOLID: Self = Self { on: 1.0, off: 0.0 };
+
+    pub const fn new(on: f32, off: f32) -> Self {
+        Self { on, off }
+    }
+
+    /// Returns true if visible after `time` seconds.
+    pub fn is_visible(&self, time: f32) -> bool {
+        if self.off <= 0.0 {
+            return true;
+        }
+        time.rem_euclid(self.on + self.off) < self.on
+    }
+}
+
+impl Default for CursorBlink {
+    fn default() -> Self {
+        Self::new(0.5, 0.5)
+    }
 }
 
 /// Marker component for a sprite containing renderred glyphs.
@@ -553,11 +678,79 @@ pub(crate) fn update_inputbox_cursor(
         }
     }
 }
+pub(crate) fn inputbox_cursor_blink(
+    time: Res<Time>,
+    global: Option<Res<CursorBlink>>,
+    mut query: Query<(&mut InputBox, Option<&CursorBlink>, ActiveDetection, &Children),
+        (Without<InputBoxText>, Without<InputBoxCursorBar>)>,
+    text: Query<&Children, With<InputBoxText>>,
+    mut bar: Query<VisibilityToggle, (With<InputBoxCursorBar>, Without<InputBoxText>, Without<InputBox>)>,
+) {
+    for (mut input_box, blink, active, children) in query.iter_mut() {
+        let Some(blink) = blink.or(global.as_deref()) else {continue};
+        // Restart the blink and show the bar whenever the caret moves.
+        let moved = input_box.is_changed();
+        let input_box = input_box.bypass_change_detection();
+        if moved {
+            input_box.blink = 0.0;
+        }
+        if !active.is_active() || !input_box.focus || input_box.cursor_len != 0 {
+            continue;
+        }
+        if !moved {
+            input_box.blink += time.delta_seconds();
+        }
+        let visible = moved || blink.is_visible(input_box.blink);
+        let Some(children) = text.iter_many(children).next() else {continue};
+        let mut iter = bar.iter_many_mut(children);
+        while let Some(mut vis) = iter.fetch_next() {
+            vis.set_visible(visible)
+        }
+    }
+}
+
 #[cfg(not(target_os = "macos"))]
 const CONTROL: [KeyCode; 2] = [KeyCode::ControlLeft, KeyCode::ControlRight];
 #[cfg(target_os = "macos")]
 const CONTROL: [KeyCode; 2] = [KeyCode::SuperLeft, KeyCode::SuperLeft];
 
+/// A [`Command`] that focuses an [`InputBox`] and removes focus from all others,
+/// so that it starts receiving keystrokes, with the cursor at the end of its text.
+///
+/// `None` removes focus from all `InputBox`es.
+///
+/// `CursorFocus` and `CursorState` reflect the cursor and are recomputed every frame,
+/// so they are left unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusInputBox(pub Option<Entity>);
+
+impl FocusInputBox {
+    pub const fn new(entity: Entity) -> Self {
+        Self(Some(entity))
+    }
+
+    pub const fn clear() -> Self {
+        Self(None)
+    }
+}
+
+impl Command for FocusInputBox {
+    fn apply(self, world: &mut World) {
+        let mut query = world.query::<(Entity, &mut InputBox)>();
+        for (entity, mut input_box) in query.iter_mut(world) {
+            let focus = self.0 == Some(entity);
+            if input_box.has_focus() == focus {
+                continue;
+            }
+            if focus {
+                let len = input_box.text.chars().count();
+                input_box.set_cursor(len, len);
+            }
+            input_box.set_focus(focus);
+        }
+    }
+}
+
 pub(crate) fn text_on_click_outside(mut query: Query<&mut InputBox, With<CursorClickOutside>>) {
     for mut input in query.iter_mut() {
         input.focus = false;
@@ -568,17 +761,24 @@ pub(crate) fn inputbox_keyboard(
     fonts: Res<Assets<Font>>,
     mut events: EventReader<ReceivedCharacter>,
     keys: Res<ButtonInput<KeyCode>>,
+    state: Res<CursorState>,
+    block: Res<InputBlock>,
     mut query: Query<(&DimensionData, &mut InputBox, &Handle<Font>,
         &Children,
         SignalSender<TextChange>,
         SignalSender<TextSubmit>,
-        ActiveDetection)>,
+        ActiveDetection,
+        Option<&mut InputPreprocessor>)>,
     text: Query<&Children, With<InputBoxText>>,
     mut bar: Query<VisibilityToggle,
         (With<InputBoxCursorBar>, Without<InputBoxCursorArea>, Without<InputBox>)>,
     mut area: Query<VisibilityToggle,
         (With<InputBoxCursorArea>, Without<InputBoxCursorBar>, Without<InputBox>)>,
 ) {
+    if state.is_blocked() || block.is_blocked() {
+        events.clear();
+        return;
+    }
     // Since the order is input -> draw text -> propagate -> move cursor
     // We can't resolve dimension on the same frame
     // therefore we hide cursor area for a frame here if it is removed 
@@ -596,7 +796,7 @@ pub(crate) fn inputbox_keyboard(
         }
     };
 
-    for (dimension, mut inputbox, font_handle, children, change, submit, active) in
+    for (dimension, mut inputbox, font_handle, children, change, submit, active, mut preprocessor) in
         query.iter_mut().filter(|(_, input, ..)| input.has_focus())
     {
         let em = dimension.em;
@@ -666,6 +866,17 @@ pub(crate) fn inputbox_keyboard(
                     }
                     "\x08" | "\x7f" => inputbox.backspace(),
                     s => {
+                        let processed;
+                        let s = match preprocessor.as_mut() {
+                            Some(preprocessor) => {
+                                processed = preprocessor.process(s);
+                                if processed.is_empty() {
+                                    continue;
+                                }
+                                processed.as_str()
+                            }
+                            None => s,
+                        };
                         if inputbox.overflow == InputOverflow::Deny {
                             let string = inputbox.try_push_str(s);
                             let font = match fonts.get(font_handle) {
@@ -697,13 +908,34 @@ pub(crate) fn inputbox_keyboard(
     }
 }
 
-/// Copy em as text size.
-pub(crate) fn sync_em_inputbox(mut query: Query<(&mut InputBox, &DimensionData)>) {
-    query.iter_mut().for_each(|(mut sp, dimension)| {
-        if sp.as_ref().em != dimension.em {
-            sp.em = dimension.em;
+pub(crate) fn inputbox_wheel_step(
+    mut query: Query<(&mut InputBox, &InputStep, &MouseWheelAction, SignalSender<TextChange>, ActiveDetection)>,
+) {
+    for (mut inputbox, step, action, change, active) in query.iter_mut() {
+        if !active.is_active() {
+            continue;
+        }
+        let lines = action.get().lines;
+        let delta = lines.x + lines.y;
+        if delta == 0 {
+            continue;
+        }
+        let Ok(value) = inputbox.get().trim().parse::<f32>() else {continue};
+        let value = (value + step.step * delta as f32).clamp(step.min, step.max);
+        let text = format!("{:.*}", step.precision, value);
+        if text != inputbox.get() {
+            inputbox.set(text);
+            change.send(inputbox.get().to_owned());
+        }
+    }
+}
+
+impl SyncEm for InputBox {
+    fn sync_em(this: &mut Mut<Self>, em: f32) {
+        if this.as_ref().em != em {
+            this.em = em;
         }
-    })
+    }
 }
 
 pub(crate) fn draw_input_box(
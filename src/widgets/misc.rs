@@ -1,7 +1,10 @@
-use bevy::{hierarchy::Children, reflect::Reflect};
-use bevy::ecs::{query::With, component::Component, entity::Entity};
+use bevy::{hierarchy::Children, reflect::Reflect, text::Text};
+use bevy::ecs::{change_detection::DetectChanges, query::With, component::Component, entity::Entity};
 use bevy::ecs::system::{Query, In};
+use bevy_defer::Object;
+use bevy_defer::signals::{SignalId, SignalReceiver, SignalSender};
 use crate::{Opacity, layout::LayoutControl};
+use crate::widgets::inputbox::{InputBox, TextSubmit};
 
 /// Limit opacity in a layout based on insertion order.
 ///
@@ -42,3 +45,228 @@ pub fn set_layout_opactiy_limit(
         }
     }
 }
+
+/// A count badge that displays its count in its [`BadgeText`] child.
+///
+/// Hidden when count is zero, displays `{max}+` if count exceeds `max`.
+///
+/// When used as a signal id, receives a new count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub struct Badge {
+    pub count: usize,
+    pub max: usize,
+}
+
+impl SignalId for Badge {
+    type Data = usize;
+}
+
+impl Badge {
+    pub const fn new(count: usize) -> Self {
+        Self { count, max: 99 }
+    }
+
+    pub const fn with_max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Obtain the displayed text.
+    pub fn text(&self) -> String {
+        if self.count > self.max {
+            format!("{}+", self.max)
+        } else {
+            self.count.to_string()
+        }
+    }
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Whether a [`Badge`]'s count is zero.
+///
+/// Use `DisplayIf(BadgeState::NonZero)` to hide a badge when its count is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Default, Reflect)]
+pub enum BadgeState {
+    #[default]
+    Zero,
+    NonZero,
+}
+
+/// Marker for the text of a [`Badge`].
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+pub struct BadgeText;
+
+pub(crate) fn badge_system(
+    mut query: Query<(&mut Badge, SignalReceiver<Badge>, &Children, &mut BadgeState)>,
+    mut text: Query<&mut Text, With<BadgeText>>,
+) {
+    for (mut badge, recv, children, mut state) in query.iter_mut() {
+        if let Some(count) = recv.poll_once() {
+            if badge.count != count {
+                badge.count = count;
+            }
+        }
+        if !badge.is_changed() {
+            continue;
+        }
+        *state = if badge.count > 0 {
+            BadgeState::NonZero
+        } else {
+            BadgeState::Zero
+        };
+        let string = badge.text();
+        let mut iter = text.iter_many_mut(children);
+        while let Some(mut text) = iter.fetch_next() {
+            if let Some(section) = text.sections.first_mut() {
+                section.value.clone_from(&string);
+            }
+        }
+    }
+}
+
+/// Sort state of a `table`, toggled by clicking its headers.
+///
+/// The table does not reorder its rows, when used as a signal id,
+/// sends the new state so rows can be sorted and rebuilt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component, Reflect)]
+pub struct TableSort {
+    /// Column being sorted by, `None` if unsorted.
+    pub column: Option<usize>,
+    pub ascending: bool,
+}
+
+impl SignalId for TableSort {
+    type Data = TableSort;
+}
+
+impl TableSort {
+    /// Sort by a column in ascending order, or flip the order if already sorted by it.
+    pub fn toggle(&mut self, column: usize) {
+        if self.column == Some(column) {
+            self.ascending = !self.ascending;
+        } else {
+            self.column = Some(column);
+            self.ascending = true;
+        }
+    }
+}
+
+/// Receives the column index as `usize` when a header of a `table` is clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub struct TableHeaderClick;
+
+impl SignalId for TableHeaderClick {
+    type Data = Object;
+}
+
+pub(crate) fn table_sort_system(
+    mut query: Query<(&mut TableSort, SignalReceiver<TableHeaderClick>, SignalSender<TableSort>)>,
+) {
+    for (mut sort, recv, send) in query.iter_mut() {
+        let Some(column) = recv.poll_once().and_then(|obj| obj.get::<usize>()) else {continue};
+        sort.toggle(column);
+        send.send(*sort);
+    }
+}
+
+/// Context of a `stepper`, a numeric `input_box` with increment and decrement buttons.
+///
+/// Text typed into the `input_box` is parsed and clamped on submit or when focus is lost,
+/// invalid text is reverted.
+///
+/// When used as a signal id, sends the new value.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct Stepper {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    /// Number of decimal places displayed.
+    pub precision: usize,
+}
+
+impl SignalId for Stepper {
+    type Data = f32;
+}
+
+impl Stepper {
+    pub const fn new(value: f32, step: f32) -> Self {
+        Self { value, min: f32::MIN, max: f32::MAX, step, precision: 0 }
+    }
+
+    pub const fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    pub const fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Clamp a value to the stepper's range.
+    pub fn clamp(&self, value: f32) -> f32 {
+        value.clamp(self.min, self.max)
+    }
+
+    /// Obtain the displayed text.
+    pub fn text(&self) -> String {
+        format!("{:.*}", self.precision, self.value)
+    }
+}
+
+/// Receives a multiplier of `step` as `f32`, sent by the buttons of a `stepper`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub struct StepperStep;
+
+impl SignalId for StepperStep {
+    type Data = Object;
+}
+
+pub(crate) fn stepper_system(
+    mut query: Query<(&mut Stepper, SignalReceiver<StepperStep>, SignalReceiver<TextSubmit>,
+        SignalSender<Stepper>, &Children)>,
+    mut inputs: Query<&mut InputBox>,
+) {
+    for (mut stepper, step, submit, send, children) in query.iter_mut() {
+        let mut value = stepper.value;
+        if let Some(delta) = step.poll_once().and_then(|obj| obj.get::<f32>()) {
+            value = stepper.clamp(value + delta * stepper.step);
+        }
+        if let Some(text) = submit.poll_once() {
+            if let Ok(parsed) = text.trim().parse::<f32>() {
+                value = stepper.clamp(parsed);
+            }
+            let mut iter = inputs.iter_many_mut(children);
+            while let Some(mut input) = iter.fetch_next() {
+                input.set_focus(false);
+            }
+        }
+        let mut iter = inputs.iter_many_mut(children);
+        while let Some(input) = iter.fetch_next() {
+            if input.has_focus() || input.get() == stepper.text() {
+                continue;
+            }
+            if let Ok(parsed) = input.get().trim().parse::<f32>() {
+                value = stepper.clamp(parsed);
+            }
+        }
+        if value != stepper.value {
+            stepper.value = value;
+            send.send(value);
+        }
+        let text = stepper.text();
+        let mut iter = inputs.iter_many_mut(children);
+        while let Some(mut input) = iter.fetch_next() {
+            if !input.has_focus() && input.get() != text {
+                input.set(text.clone());
+            }
+        }
+    }
+}
@@ -13,6 +13,12 @@
 //! | [`RadioButton`](button::RadioButton) | Context for a `radio_button`. |
 //! | [`Payload`](button::Button) | Data sent by `ButtonClick`. |
 //! | [`RadioButtonCancel`](button::RadioButtonCancel) | Allow clicking radio button again to remove its value. |
+//! | [`RadioButtonReselect`](button::RadioButtonReselect) | Move selection to the next enabled radio button if disabled. |
+//! | [`EventCooldown`](button::EventCooldown) | Suppress repeated `ButtonClick` within a time window. |
+//! | [`RepeatClick`](button::RepeatClick) | Repeat `ButtonClick` while held down. |
+//! | [`Ripple`](ripple::Ripple) | Spawn an expanding circle from the cursor when clicked. |
+//! | [`PressOffset`](button::PressOffset) | Move by an offset while pressed. |
+//! | [`Form`](form::Form) | Collect values of named input widgets on `FormSubmit`. |
 //!
 //! # Dragging And Scrolling
 //!
@@ -22,8 +28,16 @@
 //! | [`Scrolling`](scroll::Scrolling) | Enable scrolling of children. |
 //! | [`Constraint`](constraints::Constraint) | Constraint movement to the parent's dimension. |
 //! | [`ScrollDiscrete`](scroll::ScrollDiscrete) | Discrete scrolling for [`Layout`](crate::layout::Layout). |
+//! | [`DragToScroll`](scroll::DragToScroll) | Pan a scrolling container by dragging. |
+//! | [`Sticky`](scroll::Sticky) | Pin a child of a scrolling container to an edge of the viewport. |
+//! | [`OverflowFade`](scroll::OverflowFade) | Fade in an edge indicator while content overflows in that direction. |
 //! | [`DragSnapBack`](drag::DragSnapBack) | Snap dragged sprite back to the source. |
+//! | [`RaiseOnDrag`](drag::RaiseOnDrag) | Bring dragged sprite in front of its siblings. |
+//! | [`DragProxy`](drag::DragProxy) | Drag another entity from a handle. |
+//! | [`SnapGuides`](snapping::SnapGuides) | Snap dragged sprite to siblings and draw guides, requires feature `gizmos`. |
+//! | [`ResizeHandle`](drag::ResizeHandle) | Resize a target's dimension by dragging. |
 //! | [`SharedPosition`](constraints::SharedPosition) | Share position between draggable/scrollable widgets. |
+//! | [`AspectConstraint`](constraints::AspectConstraint) | Keep aspect ratio of dimension while resizing. |
 //!
 //! # Camera
 //!
@@ -31,13 +45,38 @@
 //! | --------- | ----------- |
 //! | [`ScopedCameraBundle`](clipping::ScopedCameraBundle) | Bind a camera to a sprite's `RotatedRect`. |
 //!
+//! | Component | Description |
+//! | --------- | ----------- |
+//! | [`PropagateRenderLayers`](clipping::PropagateRenderLayers) | Propagate `RenderLayers` to descendants. |
+//! | [`CameraDisplay`](clipping::CameraDisplay) | Fade the sprite displaying a camera's render target with the camera frame. |
+//!
 //! # Misc
 //!
 //! | Component | Description |
 //! | --------- | ----------- |
 //! | [`PropagateFocus`](util::PropagateFocus) | Propagate `CursorFocus` and `CheckButtonState`. |
 //! | [`SetCursor`](util::SetCursor) | Set cursor icon during some cursor events. |
+//! | [`CursorPriority`](util::CursorPriority) | Priority of `SetCursor` if multiple sprites set the cursor. |
+//! | [`SetCursorIf`](util::SetCursorIf) | Override the icon of `SetCursor` based on a state. |
+//! | [`WidgetState`](util::WidgetState) | Hovered, pressed, disabled and checked state for styling. |
 //! | [`DisplayIf`](util::DisplayIf) | Display if some condition is met. |
+//! | [`DisplayIfFade`](util::DisplayIfFade) | Fade `DisplayIf` in and out instead of toggling. |
+//! | [`CursorStateAnim`](util::CursorStateAnim) | Animate a value by idle, hover, pressed and disabled states. |
+//! | [`DespawnOnSignal`](util::DespawnOnSignal) | Despawn recursively when a signal is received. |
+//! | [`EnabledIf`](util::EnabledIf) | Enable or disable when a `bool` signal is received. |
+//! | [`HoverExpand`](util::HoverExpand) | Expand width while hovered, collapse after a short delay. |
+//! | [`CrossFadeSprite`](util::CrossFadeSprite) | Cross-fade between two sprites by opacity. |
+//! | [`Badge`](misc::Badge) | Display a count, hidden if zero. |
+//! | [`BadgeState`](misc::BadgeState) | Whether a `Badge` is empty, used with `DisplayIf`. |
+//! | [`TableSort`](misc::TableSort) | Sort state of a `table`, toggled by clicking its headers. |
+//! | [`Stepper`](misc::Stepper) | Value of a `stepper`, clamped and sent on change. |
+//! | [`ColorPicker`](color_picker::ColorPicker) | Context of a `color_picker`, sends the picked color. |
+//! | [`TextOutline`](TextOutline) | Render an outline behind text. |
+//! | [`FontFallback`](FontFallback) | Render glyphs missing from the primary font with fallback fonts. |
+//! | [`MaxLines`](MaxLines) | Truncate text to a number of lines with an ellipsis. |
+//! | [`TextHighlight`](TextHighlight) | Render rectangles behind ranges of text. |
+//! | [`TextBinding`](signals::TextBinding) | Write formatted data from a signal to a text widget. |
+//! | `SoundOnEvent` | Play a sound on cursor events, requires feature `audio`. |
 //!
 //! # InputBox
 //!
@@ -47,6 +86,9 @@
 //! | [`InputBoxText`](inputbox::InputBoxText) | Marker for a container of glyphs in an `input_box` |
 //! | [`InputBoxCursorBar`](inputbox::InputBoxCursorBar) | Bar for a cursor. |
 //! | [`InputBoxCursorArea`](inputbox::InputBoxCursorArea) | Area for a cursor. |
+//! | [`InputStep`](inputbox::InputStep) | Increment a numeric `input_box` with the mouse wheel. |
+//! | [`InputPreprocessor`](inputbox::InputPreprocessor) | Transform or veto characters typed into an `input_box`. |
+//! | [`CursorBlink`](inputbox::CursorBlink) | Blink rate of the cursor, also available as a resource. |
 //!
 //! # RichText
 //!
@@ -60,21 +102,30 @@ pub mod richtext;
 pub mod scroll;
 pub mod clipping;
 pub mod button;
+pub mod form;
+pub mod color_picker;
 pub mod spinner;
 pub mod util;
 pub mod signals;
+#[cfg(feature="audio")]
+pub mod sound;
 mod text;
 use bevy::ecs::system::IntoSystem;
-pub use text::TextFragment;
+pub use text::{TextFragment, FontFallback, MaxLines, TextOutline, TextOutlineCopy, TextHighlight, TextHighlightRect};
 pub mod constraints;
 mod atlas;
 pub mod misc;
+pub mod ripple;
+#[cfg(feature="gizmos")]
+pub mod snapping;
 pub use atlas::DeferredAtlasBuilder;
+#[cfg(feature="atlas_json")]
+pub use atlas::{AtlasJsonLoader, AtlasJsonError};
 use bevy::ecs::schedule::IntoSystemConfigs;
 use bevy::app::{Plugin, PreUpdate, Update, PostUpdate, Last};
 
 use crate::events::{CursorAction, CursorFocus};
-use crate::schedule::{CleanupSet, LoadInputSet, PostEventSet, PostWidgetEventSet, StoreOutputSet, WidgetEventSet};
+use crate::schedule::{CleanupSet, EventSet, LoadInputSet, PostEventSet, PostWidgetEventSet, StoreOutputSet, WidgetEventSet};
 
 use self::button::CheckButtonState;
 use self::inputbox::InputBoxState;
@@ -83,11 +134,34 @@ pub(crate) struct WidgetsPlugin;
 
 impl Plugin for WidgetsPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
+        #[cfg(feature="atlas_json")]
+        {
+            use bevy::asset::AssetApp;
+            app.init_asset_loader::<AtlasJsonLoader>();
+        }
+        #[cfg(feature="audio")]
+        app.add_systems(Update, sound::sound_on_event);
+        #[cfg(feature="debug")]
+        app.add_systems(Last, crate::util::inspect_signals);
+        #[cfg(feature="gizmos")]
+        app.add_systems(PostUpdate, (
+                crate::util::draw_layout_gizmos,
+                snapping::draw_snap_guides,
+            ).after(crate::schedule::FinalizeSet))
+            .add_systems(PreUpdate, snapping::snap_to_guides
+                .in_set(WidgetEventSet)
+                .after(drag::dragging));
         app
+            .add_systems(PreUpdate, button::button_on_key
+                .in_set(EventSet)
+                .after(crate::events::mouse_button_input))
             .add_systems(PreUpdate, (
                 button::button_on_click,
+                button::repeat_click,
+                ripple::ripple_on_click,
                 button::check_button_on_click,
                 button::radio_button_on_click,
+                button::radio_button_reselect,
                 button::generate_check_button_state,
                 scroll::propagate_mouse_wheel_action,
                 util::propagate_focus::<CursorAction>,
@@ -96,29 +170,55 @@ impl Plugin for WidgetsPlugin {
             .add_systems(PreUpdate, (
                 inputbox::update_inputbox_cursor
                     .before(inputbox::inputbox_keyboard),
+                inputbox::inputbox_cursor_blink
+                    .after(inputbox::update_inputbox_cursor)
+                    .after(inputbox::inputbox_keyboard),
                 inputbox::text_on_mouse_down,
                 inputbox::text_on_click_outside,
                 inputbox::text_on_mouse_double_click,
                 inputbox::inputbox_keyboard,
+                inputbox::inputbox_wheel_step,
                 inputbox::text_propagate_focus,
                 drag::drag_start,
                 drag::drag_end,
                 drag::dragging.after(drag::drag_start),
+                drag::raise_on_drag.after(drag::drag_start),
+                drag::resize_handle_start,
+                drag::resize_handle_dragging.after(drag::resize_handle_start),
                 scroll::scrolling_senders,
                 (
                     scroll::scrolling_system,
                     scroll::scroll_discrete_system,
                 ).after(scroll::scrolling_senders),
+                scroll::drag_to_scroll_system.after(scroll::scrolling_system),
+                scroll::sticky_system
+                    .after(scroll::scrolling_system)
+                    .after(scroll::drag_to_scroll_system),
+                scroll::overflow_fade_system.after(scroll::sticky_system),
                 clipping::sync_camera_dimension,
             ).in_set(WidgetEventSet))
             .add_systems(PreUpdate, (
                 util::propagate_focus::<CheckButtonState>,
                 inputbox::text_propagate_focus,
             ).in_set(PostWidgetEventSet))
+            .add_systems(PreUpdate, (
+                bevy::ecs::prelude::apply_deferred,
+                util::widget_state_system,
+            ).chain().after(PostWidgetEventSet))
             .add_systems(Update, (
-                util::set_cursor,
+                (util::set_cursor_if::<CheckButtonState>, util::set_cursor).chain(),
                 util::event_conditional_visibility,
                 util::check_conditional_visibility,
+                util::badge_conditional_visibility
+                    .after(misc::badge_system),
+                util::display_if_fade_init
+                    .before(util::event_conditional_visibility)
+                    .before(util::check_conditional_visibility)
+                    .before(util::badge_conditional_visibility),
+                util::display_if_fade_hit_test
+                    .after(util::event_conditional_visibility)
+                    .after(util::check_conditional_visibility)
+                    .after(util::badge_conditional_visibility),
                 inputbox::draw_input_box
                     .before(text::sync_text_text_fragment)
                     .before(text::sync_sprite_text_fragment),
@@ -129,19 +229,51 @@ impl Plugin for WidgetsPlugin {
                 spinner::spin_text_change,
                 spinner::sync_spin_text_with_text,
                 signals::sig_set_text,
+                signals::sig_text_binding,
                 signals::radio_button_clear_widget,
                 signals::inputbox_clear_widget,
                 signals::text_clear_widget,
+                form::form_on_submit,
             ))
             .add_systems(Update, (
                 misc::layout_opacity_limit.pipe(misc::set_layout_opactiy_limit),
+                misc::badge_system,
+                misc::table_sort_system,
+                misc::stepper_system,
+                ripple::ripple_despawn,
+                button::press_offset,
+                util::cursor_state_anim::<bevy::render::color::Color>,
+                util::cursor_state_anim::<crate::anim::Offset>,
+                util::cursor_state_anim::<crate::anim::Scale>,
+                util::cursor_state_anim::<crate::anim::Rotation>,
+                clipping::propagate_render_layers,
+                clipping::sync_camera_opacity,
+                color_picker::color_picker_system,
+                color_picker::color_picker_handle_init,
+                constraints::aspect_constraint_system,
+                util::despawn_on_signal::<button::ButtonClick>,
+                util::despawn_on_signal::<signals::Invocation>,
+                util::enabled_if,
+                util::hover_expand,
+                util::cross_fade_sprite,
             ))
             .add_systems(PostUpdate, (
                 richtext::synchronize_glyph_spaces
             ).in_set(LoadInputSet))
             .add_systems(PostUpdate, (
                 text::sync_em_text_fragment,
-                inputbox::sync_em_inputbox
+                crate::sync_em::<inputbox::InputBox>,
+                text::font_fallback_system
+                    .before(text::sync_text_outline),
+                text::max_lines_system
+                    .after(text::font_fallback_system)
+                    .before(text::sync_text_outline),
+                text::sync_text_outline
+                    .after(crate::core::systems::sync_em::<bevy::text::Text>)
+                    .after(crate::core::systems::sync_opacity_text),
+                text::remove_text_outline,
+                text::sync_text_highlight,
+                text::remove_text_highlight,
             ).in_set(StoreOutputSet))
             .add_systems(Last, util::remove_all::<CheckButtonState>.in_set(CleanupSet))
             .add_systems(Last, util::remove_all::<InputBoxState>.in_set(CleanupSet))
@@ -0,0 +1,125 @@
+use bevy::asset::Handle;
+use bevy::ecs::{component::Component, entity::Entity};
+use bevy::ecs::system::{Commands, Query, Res};
+use bevy::hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy::math::Vec2;
+use bevy::render::{color::Color, texture::Image};
+use bevy::sprite::Sprite;
+use bevy::time::Time;
+
+use crate::anim::{Easing, Interpolate, Scale};
+use crate::bundles::{BuildTransformBundle, RectrayBundle};
+use crate::events::{CursorAction, CursorState, EventFlags};
+use crate::layout::LayoutControl;
+use crate::{Anchor, Coloring, Dimension, DimensionData, Opacity, RotatedRect, Size2, Transform2D};
+
+/// Spawns an expanding, fading circle from the cursor when clicked.
+///
+/// Requires `EventFlags::LeftClick`. This crate provides no shapes,
+/// `texture` should be a circle, tinted by `color`.
+///
+/// By default the circle grows until it covers the widget.
+/// If `contained` is set, the circle is kept inside the widget's bounds instead,
+/// for exact clipping place the widget in a `camera_frame`.
+#[derive(Debug, Clone, Component)]
+pub struct Ripple {
+    pub texture: Handle<Image>,
+    pub color: Color,
+    /// Duration of the ripple in seconds.
+    pub duration: f32,
+    pub easing: Easing,
+    /// If set, keep the ripple inside the widget's bounds.
+    pub contained: bool,
+    /// Z of the ripple relative to the widget.
+    pub z: f32,
+}
+
+impl Ripple {
+    pub fn new(texture: Handle<Image>, color: Color) -> Self {
+        Self {
+            texture,
+            color,
+            duration: 0.4,
+            easing: Easing::Ease(crate::anim::EaseFunction::QuadraticOut),
+            contained: false,
+            z: 0.001,
+        }
+    }
+
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Keep the ripple inside the widget's bounds.
+    pub fn contained(mut self) -> Self {
+        self.contained = true;
+        self
+    }
+}
+
+/// Marker and remaining lifetime of a circle spawned by [`Ripple`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct RippleEffect {
+    remaining: f32,
+}
+
+pub(crate) fn ripple_on_click(
+    mut commands: Commands,
+    state: Res<CursorState>,
+    query: Query<(Entity, &Ripple, &CursorAction, &RotatedRect, &DimensionData)>,
+) {
+    for (entity, ripple, action, rect, dimension) in query.iter() {
+        if !action.is(EventFlags::LeftClick) {
+            continue;
+        }
+        let half = dimension.size / 2.0;
+        let mut center = rect.local_space(state.cursor_position()) / rect.scale;
+        let radius = if ripple.contained {
+            let radius = half.min_element();
+            center = center.clamp(-half + radius, half - radius);
+            radius
+        } else {
+            (center.abs() + half).length()
+        };
+        let circle = commands.spawn((
+            RectrayBundle {
+                transform: Transform2D::UNIT
+                    .with_anchor(Anchor::CENTER)
+                    .with_offset(Size2::pixels(center.x, center.y))
+                    .with_scale(Vec2::ZERO)
+                    .with_z(ripple.z),
+                dimension: Dimension::owned(Size2::pixels(radius * 2.0, radius * 2.0)),
+                control: LayoutControl::IgnoreLayout,
+                ..Default::default()
+            },
+            Sprite::default(),
+            ripple.texture.clone(),
+            Coloring::new(ripple.color),
+            BuildTransformBundle::default(),
+            Interpolate::<Scale>::init(ripple.easing, (Vec2::ZERO, Vec2::ONE), ripple.duration),
+            Interpolate::<Opacity>::init(ripple.easing, (1.0, 0.0), ripple.duration),
+            RippleEffect { remaining: ripple.duration },
+        )).id();
+        commands.entity(entity).add_child(circle);
+    }
+}
+
+pub(crate) fn ripple_despawn(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut RippleEffect)>,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut effect) in query.iter_mut() {
+        effect.remaining -= delta;
+        if effect.remaining <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
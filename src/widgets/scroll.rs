@@ -5,11 +5,11 @@ use bevy::ecs::query::{Has, With};
 use bevy::ecs::system::Commands;
 use bevy::math::{Vec2, IVec2};
 use bevy::ecs::{component::Component, query::Without};
-use bevy::ecs::system::Query;
+use bevy::ecs::system::{Query, Res};
 use bevy_defer::signals::{SignalId, SignalReceiver, SignalSender};
 use crate::util::{Rem, WindowSize};
-use crate::{Transform2D, anim::Attr, anim::Offset, DimensionData};
-use crate::events::MouseWheelAction;
+use crate::{Anchor, Transform2D, anim::Attr, anim::Offset, anim::Interpolate, DimensionData, Opacity, RotatedRect};
+use crate::events::{CursorFocus, CursorState, EventFlags, MouseWheelAction};
 use crate::layout::Container;
 
 use crate::events::MovementUnits;
@@ -46,6 +46,8 @@ pub struct ScrollParent;
 ///     For example synchronizing a scrollbar with a textbox.
 /// * [`PositionFac`](super::constraints::PositionFac): A signal that sends a value
 ///     in `0..=1` in its constraints when being scrolled.
+/// * [`ScrollFraction`](super::constraints::ScrollFraction): A signal that sends a `Vec2`
+///     in `0..=1` on both axes, for driving two scrollbars.
 #[derive(Debug, Clone, Copy, Component, Reflect)]
 pub struct Scrolling {
     pub pos_x: bool,
@@ -209,6 +211,70 @@ pub(crate) fn scrolling_system(
     }
 }
 
+/// Allows a [`Scrolling`] container to be panned by dragging.
+///
+/// Requires the corresponding drag `EventFlags`, like `MidDrag`.
+/// Since drag targets are determined on press,
+/// drags that begin on an interactive child do not pan the container.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct DragToScroll {
+    /// Drag events that pan the container, default is `MidDrag`.
+    pub flags: EventFlags,
+    last: Option<Vec2>,
+}
+
+impl DragToScroll {
+    pub const fn new(flags: EventFlags) -> Self {
+        Self { flags, last: None }
+    }
+}
+
+impl Default for DragToScroll {
+    fn default() -> Self {
+        Self::new(EventFlags::MidDrag)
+    }
+}
+
+pub(crate) fn drag_to_scroll_system(
+    window_size: WindowSize,
+    rem: Rem,
+    state: Res<CursorState>,
+    mut query: Query<(
+        Entity, Option<&Parent>, &Scrolling, &mut DragToScroll, &DimensionData,
+        Attr<Transform2D, Offset>, Option<&CursorFocus>,
+    )>,
+    mut constraints: Query<ConstraintQuery, With<Constraint>>,
+    parent_query: Query<&DimensionData>,
+) {
+    let window_size = window_size.get();
+    let rem = rem.get();
+    for (entity, parent, scroll, mut drag, dim, mut transform, focus) in query.iter_mut() {
+        if !focus.map(|x| x.intersects(drag.flags)).unwrap_or(false) {
+            drag.last = None;
+            continue;
+        }
+        let cursor = state.cursor_position();
+        let last = drag.last.unwrap_or(state.down_position());
+        drag.last = Some(cursor);
+        let delta = cursor - last;
+        let delta = Vec2::new(
+            if scroll.x_scroll() {delta.x} else {0.0},
+            if scroll.y_scroll() {delta.y} else {0.0},
+        );
+        if delta == Vec2::ZERO {
+            continue;
+        }
+        let parent = parent
+            .and_then(|x| parent_query.get(**x).ok())
+            .map(|x| x.size)
+            .unwrap_or(window_size);
+        transform.force_set_pixels(transform.get_pixels(parent, dim.em, rem) + delta);
+        if let Ok(constraints) = constraints.get_mut(entity) {
+            constraint_system(constraints, &mut transform, scroll.x_scroll(), scroll.y_scroll(), parent, rem)
+        }
+    }
+}
+
 /// Marker component for making scrolling affect
 /// the `range` value on a layout.
 ///
@@ -272,3 +338,157 @@ pub(crate) fn scroll_discrete_system(
         }
     }
 }
+
+/// Pins a child of a [`Scrolling`] container to an edge of its viewport
+/// when scrolled past that edge, i.e. a sticky header.
+///
+/// Multiple sticky children on the same edge stack on top of each other.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct Sticky {
+    /// Edge of the viewport to pin to, like `Anchor::TOP_CENTER`.
+    pub edge: Anchor,
+    correction: Vec2,
+    content_offset: Vec2,
+}
+
+impl Sticky {
+    pub const fn new(edge: Anchor) -> Self {
+        Self {
+            edge,
+            correction: Vec2::ZERO,
+            content_offset: Vec2::ZERO,
+        }
+    }
+}
+
+impl Default for Sticky {
+    fn default() -> Self {
+        Self::new(Anchor::TOP_CENTER)
+    }
+}
+
+pub(crate) fn sticky_system(
+    rem: Rem,
+    scroll: Query<(&Parent, &Children, &Transform2D, &DimensionData), With<Scrolling>>,
+    viewport: Query<(&RotatedRect, &DimensionData)>,
+    mut sticky: Query<(&mut Sticky, &RotatedRect, Attr<Transform2D, Offset>), Without<Scrolling>>,
+) {
+    let rem = rem.get();
+    for (parent, children, transform, dim) in scroll.iter() {
+        let Ok((viewport, viewport_dim)) = viewport.get(parent.get()) else {continue};
+        let content_offset = transform.offset.as_pixels(viewport_dim.size, dim.em, rem);
+        let half = viewport.half_dim();
+        // Rects are from the last frame, compensate for this frame's scrolling.
+        let mut items: Vec<_> = children.iter().filter_map(|child| {
+            let (sticky, rect, _) = sticky.get(*child).ok()?;
+            let natural = viewport.local_space(rect.center()) - sticky.correction
+                + content_offset - sticky.content_offset;
+            let priority = natural.dot(sticky.edge.as_vec());
+            Some((*child, natural, rect.half_dim(), priority))
+        }).collect();
+        // Items closest to their edge are pinned first.
+        items.sort_by(|a, b| b.3.total_cmp(&a.3));
+        // left, right, bottom, top
+        let mut inset = [0.0f32; 4];
+        for (child, natural, child_half, _) in items {
+            let Ok((mut sticky, _, mut offset)) = sticky.get_mut(child) else {continue};
+            let edge = sticky.edge;
+            let mut correction = Vec2::ZERO;
+            if edge.x() < 0.0 {
+                let min = -half.x + inset[0] + child_half.x;
+                if natural.x < min {
+                    correction.x = min - natural.x;
+                    inset[0] += child_half.x * 2.0;
+                }
+            } else if edge.x() > 0.0 {
+                let max = half.x - inset[1] - child_half.x;
+                if natural.x > max {
+                    correction.x = max - natural.x;
+                    inset[1] += child_half.x * 2.0;
+                }
+            }
+            if edge.y() < 0.0 {
+                let min = -half.y + inset[2] + child_half.y;
+                if natural.y < min {
+                    correction.y = min - natural.y;
+                    inset[2] += child_half.y * 2.0;
+                }
+            } else if edge.y() > 0.0 {
+                let max = half.y - inset[3] - child_half.y;
+                if natural.y > max {
+                    correction.y = max - natural.y;
+                    inset[3] += child_half.y * 2.0;
+                }
+            }
+            if correction != sticky.correction {
+                let pixels = offset.get_pixels(dim.size, dim.em, rem);
+                offset.force_set_pixels(pixels - sticky.correction + correction);
+                sticky.correction = correction;
+            }
+            sticky.content_offset = content_offset;
+        }
+    }
+}
+
+/// Fades a child of a [`Scrolling`] container's viewport in when content
+/// can still be scrolled towards `edge`, i.e. an overflow indicator.
+///
+/// Place this on a sibling of the [`Scrolling`] entity, usually a gradient
+/// sprite on the edge, alongside `Interpolate<Opacity>`.
+/// Fades on opposite edges can be visible at the same time.
+///
+/// This matches the [`ScrollFraction`](super::constraints::ScrollFraction) signal
+/// not being at that end, but is also correct before the first scroll.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct OverflowFade {
+    /// Edge of the viewport to check, like `Anchor::BOTTOM_CENTER`.
+    pub edge: Anchor,
+    /// Minimum overflow in pixels to show the fade, default is `1.0`.
+    pub threshold: f32,
+}
+
+impl OverflowFade {
+    pub const fn new(edge: Anchor) -> Self {
+        Self {
+            edge,
+            threshold: 1.0,
+        }
+    }
+}
+
+impl Default for OverflowFade {
+    fn default() -> Self {
+        Self::new(Anchor::BOTTOM_CENTER)
+    }
+}
+
+pub(crate) fn overflow_fade_system(
+    scroll: Query<(&Parent, &RotatedRect), With<Scrolling>>,
+    viewport: Query<(&RotatedRect, &Children)>,
+    mut fades: Query<(&OverflowFade, &mut Interpolate<Opacity>), Without<Scrolling>>,
+) {
+    for (parent, rect) in scroll.iter() {
+        let Ok((viewport, children)) = viewport.get(parent.get()) else {continue};
+        let half = viewport.half_dim();
+        let center = viewport.local_space(rect.center());
+        let min = center - rect.half_dim();
+        let max = center + rect.half_dim();
+        // left, right, bottom, top
+        let overflow = [-half.x - min.x, max.x - half.x, -half.y - min.y, max.y - half.y];
+        let mut iter = fades.iter_many_mut(children);
+        while let Some((fade, mut opacity)) = iter.fetch_next() {
+            let mut remaining = 0.0f32;
+            if fade.edge.x() < 0.0 {
+                remaining = remaining.max(overflow[0]);
+            } else if fade.edge.x() > 0.0 {
+                remaining = remaining.max(overflow[1]);
+            }
+            if fade.edge.y() < 0.0 {
+                remaining = remaining.max(overflow[2]);
+            } else if fade.edge.y() > 0.0 {
+                remaining = remaining.max(overflow[3]);
+            }
+            opacity.interpolate_to(if remaining > fade.threshold {1.0} else {0.0});
+        }
+    }
+}
@@ -9,6 +9,8 @@ use bevy::{ecs::{component::Component, query::{With, Without}, system::Query}, t
 use bevy_defer::{signal_ids, AsObject, Object};
 use bevy_defer::signals::{SignalId, SignalReceiver};
 
+use crate::dsl::WidgetWrite;
+
 use super::{button::RadioButton, inputbox::InputBox, TextFragment};
 
 mod sealed {
@@ -33,8 +35,45 @@ signal_ids!(
     pub Invocation: Object,
     /// A standard signal that removes data from a widget.
     pub ClearWidget: Object,
+    /// A standard signal that sends data to a [`TextBinding`].
+    pub BindText: Object,
 );
 
+type Formatter = Box<dyn FnMut(&Object) -> Option<String> + Send + Sync>;
+
+/// Uses signal `BindText` for writing formatted data to a text widget.
+///
+/// A lightweight alternative to an async system for simple data binding,
+/// text is only rewritten when the received value changed.
+#[derive(Component)]
+pub struct TextBinding {
+    fmt: Formatter,
+}
+
+impl std::fmt::Debug for TextBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextBinding").finish_non_exhaustive()
+    }
+}
+
+impl TextBinding {
+    /// Format values of type `T`, values of other types are ignored.
+    pub fn new<T: AsObject + PartialEq>(fmt: impl Fn(&T) -> String + Send + Sync + 'static) -> Self {
+        let mut last = None;
+        Self {
+            fmt: Box::new(move |obj| {
+                let value = obj.get::<T>()?;
+                if last.as_ref() == Some(&value) {
+                    return None;
+                }
+                let string = fmt(&value);
+                last = Some(value);
+                Some(string)
+            })
+        }
+    }
+}
+
 /// Uses signal `SetText` fot setting Text.
 #[derive(Debug, Clone, Copy, Default, Component)]
 #[component(storage="SparseSet")]
@@ -62,6 +101,21 @@ pub(crate) fn sig_set_text(
     }
 }
 
+pub(crate) fn sig_text_binding(
+    mut q: Query<(&mut TextBinding, SignalReceiver<BindText>, Option<&mut TextFragment>, Option<&mut Text>, Option<&mut InputBox>)>) {
+    for (mut binding, recv, frag, text, input) in q.iter_mut() {
+        let Some(obj) = recv.poll_once() else {continue};
+        let Some(str) = (binding.fmt)(&obj) else {continue};
+        if let Some(frag) = frag {
+            frag.write(str)
+        } else if let Some(input) = input {
+            input.write(str)
+        } else if let Some(text) = text {
+            text.write(str)
+        }
+    }
+}
+
 pub(crate) fn inputbox_clear_widget(
     mut q: Query<(SignalReceiver<ClearWidget>, &mut InputBox)>
 ) {
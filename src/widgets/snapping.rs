@@ -0,0 +1,188 @@
+use bevy::ecs::{component::Component, entity::Entity, query::{With, Without}};
+use bevy::ecs::system::Query;
+use bevy::gizmos::gizmos::Gizmos;
+use bevy::hierarchy::{Children, Parent};
+use bevy::math::Vec2;
+use bevy::render::color::Color;
+
+use crate::anim::{Attr, Offset};
+use crate::events::{CursorFocus, EventFlags};
+use crate::util::WindowSize;
+use crate::{Anchor, DimensionData, RotatedRect, Transform2D};
+
+use super::drag::Dragging;
+
+/// Snap a dragged sprite's edges and center to those of its siblings and its parent,
+/// drawing guide lines with gizmos, requires feature `gizmos`.
+///
+/// Requires [`Dragging`] and an offset in pixels.
+/// The dragged sprite is assumed to be not rotated or scaled.
+#[derive(Debug, Clone, Component)]
+pub struct SnapGuides {
+    /// Maximum distance in pixels to snap from.
+    pub tolerance: f32,
+    /// Color of the guide lines.
+    pub color: Color,
+    /// If set, also snap to the parent's edges and center.
+    pub parent: bool,
+    guides: Vec<[Vec2; 2]>,
+}
+
+impl SnapGuides {
+    pub fn new(tolerance: f32) -> Self {
+        Self {
+            tolerance,
+            color: Color::CYAN,
+            parent: true,
+            guides: Vec::new(),
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Only snap to siblings.
+    pub fn without_parent(mut self) -> Self {
+        self.parent = false;
+        self
+    }
+
+    /// Guide lines in world space of the current frame.
+    pub fn guides(&self) -> &[[Vec2; 2]] {
+        &self.guides
+    }
+}
+
+impl Default for SnapGuides {
+    fn default() -> Self {
+        Self::new(6.0)
+    }
+}
+
+/// Edges and center on an axis, with the span on the other axis.
+#[derive(Debug, Clone, Copy)]
+struct Lines {
+    lines: [f32; 3],
+    span: (f32, f32),
+}
+
+impl Lines {
+    fn new(center: Vec2, half: Vec2) -> [Self; 2] {
+        [
+            Lines {
+                lines: [center.x - half.x, center.x, center.x + half.x],
+                span: (center.y - half.y, center.y + half.y),
+            },
+            Lines {
+                lines: [center.y - half.y, center.y, center.y + half.y],
+                span: (center.x - half.x, center.x + half.x),
+            },
+        ]
+    }
+}
+
+/// Find the closest line within tolerance, returns `(distance, line, target span)`.
+fn closest(this: &Lines, targets: &[Lines], tolerance: f32) -> Option<(f32, f32, (f32, f32))> {
+    let mut best: Option<(f32, f32, (f32, f32))> = None;
+    for target in targets {
+        for line in target.lines {
+            for source in this.lines {
+                let distance = line - source;
+                if distance.abs() > tolerance || best.is_some_and(|(d, ..)| d.abs() <= distance.abs()) {
+                    continue;
+                }
+                best = Some((distance, line, target.span));
+            }
+        }
+    }
+    best
+}
+
+pub(crate) fn snap_to_guides(
+    window_size: WindowSize,
+    mut query: Query<(Entity, &mut SnapGuides, Option<&Parent>, Attr<Transform2D, Offset>,
+        &DimensionData, Option<&CursorFocus>), With<Dragging>>,
+    rects: Query<&RotatedRect>,
+    dimensions: Query<&DimensionData>,
+    children: Query<&Children>,
+    roots: Query<Entity, (With<RotatedRect>, Without<Parent>)>,
+) {
+    let window_size = window_size.get();
+    for (entity, mut snap, parent, mut transform, dimension, focus) in query.iter_mut() {
+        snap.guides.clear();
+        if !focus.is_some_and(|x| x.intersects(EventFlags::AnyDrag)) {
+            continue;
+        }
+        let Some(offset) = transform.component.offset.get_pixels() else {continue};
+        let parent = parent.map(|x| x.get());
+        let parent_rect = parent.and_then(|x| rects.get(x).ok()).copied();
+        let parent_size = parent
+            .and_then(|x| dimensions.get(x).ok())
+            .map(|x| x.size)
+            .unwrap_or(window_size);
+        let siblings: Vec<Entity> = match parent {
+            Some(parent) => children.get(parent).map(|x| x.to_vec()).unwrap_or_default(),
+            None => roots.iter().collect(),
+        };
+
+        let anchor = transform.component.anchor.or(Anchor::CENTER).as_vec();
+        let parent_anchor = transform.component.get_parent_anchor().or(Anchor::CENTER).as_vec();
+        let half = dimension.size / 2.0;
+        let center = parent_anchor * parent_size + offset - anchor * dimension.size;
+
+        let mut targets_x = Vec::new();
+        let mut targets_y = Vec::new();
+        if snap.parent {
+            let [x, y] = Lines::new(Vec2::ZERO, parent_size / 2.0);
+            targets_x.push(x);
+            targets_y.push(y);
+        }
+        for sibling in siblings.into_iter().filter(|x| *x != entity) {
+            let Ok(rect) = rects.get(sibling) else {continue};
+            let rect = match &parent_rect {
+                Some(parent) => rect.relative_to(parent),
+                None => *rect,
+            };
+            let [x, y] = Lines::new(rect.center(), rect.half_dim());
+            targets_x.push(x);
+            targets_y.push(y);
+        }
+
+        let [this_x, this_y] = Lines::new(center, half);
+        let snap_x = closest(&this_x, &targets_x, snap.tolerance);
+        let snap_y = closest(&this_y, &targets_y, snap.tolerance);
+        let delta = Vec2::new(
+            snap_x.map(|(d, ..)| d).unwrap_or(0.0),
+            snap_y.map(|(d, ..)| d).unwrap_or(0.0),
+        );
+        if delta != Vec2::ZERO {
+            transform.force_set_pixels(offset + delta);
+        }
+
+        let to_world = |local: Vec2| match &parent_rect {
+            Some(parent) => parent.center() + Vec2::from_angle(parent.rotation).rotate(local * parent.scale),
+            None => local,
+        };
+        if let Some((_, x, (min, max))) = snap_x {
+            let (min, max) = (min.min(this_x.span.0 + delta.y), max.max(this_x.span.1 + delta.y));
+            snap.guides.push([to_world(Vec2::new(x, min)), to_world(Vec2::new(x, max))]);
+        }
+        if let Some((_, y, (min, max))) = snap_y {
+            let (min, max) = (min.min(this_y.span.0 + delta.x), max.max(this_y.span.1 + delta.x));
+            snap.guides.push([to_world(Vec2::new(min, y)), to_world(Vec2::new(max, y))]);
+        }
+    }
+}
+
+pub(crate) fn draw_snap_guides(
+    mut gizmos: Gizmos,
+    query: Query<&SnapGuides>,
+) {
+    for snap in query.iter() {
+        for [a, b] in snap.guides() {
+            gizmos.line_2d(*a, *b, snap.color);
+        }
+    }
+}
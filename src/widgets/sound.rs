@@ -0,0 +1,46 @@
+use bevy::asset::Handle;
+use bevy::audio::{AudioBundle, AudioSource, PlaybackSettings};
+use bevy::ecs::{component::Component, system::{Commands, Query}};
+
+use crate::events::{CursorAction, EventFlags};
+
+/// Play a sound when a matching `CursorAction` is received.
+///
+/// Only edge triggered `CursorAction` flags like `LeftClick`, `Down`,
+/// `DoubleClick` or `HoverEnter` are checked,
+/// level triggered `CursorFocus` flags like `Hover` will not play the sound.
+#[derive(Debug, Clone, Component)]
+pub struct SoundOnEvent {
+    pub flag: EventFlags,
+    pub handle: Handle<AudioSource>,
+    pub settings: PlaybackSettings,
+}
+
+impl SoundOnEvent {
+    pub fn new(flag: EventFlags, handle: Handle<AudioSource>) -> Self {
+        Self {
+            flag,
+            handle,
+            settings: PlaybackSettings::DESPAWN,
+        }
+    }
+
+    pub fn with_settings(mut self, settings: PlaybackSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+}
+
+pub(crate) fn sound_on_event(
+    mut commands: Commands,
+    query: Query<(&CursorAction, &SoundOnEvent)>,
+) {
+    for (action, sound) in query.iter() {
+        if action.intersects(sound.flag) {
+            commands.spawn(AudioBundle {
+                source: sound.handle.clone(),
+                settings: sound.settings,
+            });
+        }
+    }
+}
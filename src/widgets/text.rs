@@ -1,11 +1,18 @@
 use bevy::{asset::{Assets, Handle}, reflect::Reflect, render::render_asset::RenderAssetUsages};
-use bevy::render::texture::Image;
+use bevy::hierarchy::{BuildChildren, Children, DespawnRecursiveExt, Parent};
+use bevy::math::Vec2;
+use bevy::render::{color::Color, texture::Image};
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
-use bevy::text::{Font, Text, TextStyle};
-use bevy::ecs::{component::Component, query::Changed, world::Mut};
-use bevy::ecs::system::{Query, Res, ResMut};
+use bevy::text::{Font, Text, Text2dBounds, TextLayoutInfo, TextSection, TextStyle};
+use bevy::ecs::{change_detection::DetectChanges, component::Component, entity::Entity, query::{Changed, Or, With, Without}, world::{Mut, Ref}};
+use bevy::sprite::Sprite;
+use std::ops::Range;
+use bevy::ecs::system::{Commands, Query, Res, ResMut};
+use bevy::ecs::removal_detection::RemovedComponents;
 
-use crate::{DimensionData, util::ScalingFactor, Coloring};
+use crate::bundles::{BuildTransformBundle, RectrayBundle};
+use crate::core::systems::{OptOutFontSizeSync, OptOutTextBoundsSync};
+use crate::{Dimension, DimensionData, util::ScalingFactor, Coloring, Opacity, Size2, Transform2D};
 
 use ab_glyph::{Font as _, point};
 use ab_glyph::ScaleFont as _;
@@ -60,6 +67,238 @@ impl TextFragment {
     }
 }
 
+/// Fallback fonts for glyphs missing from the primary font of a [`Text`].
+///
+/// Each section of the text is split separately, runs of characters its font cannot render
+/// become separate `TextSection`s using the first font in the chain that contains them,
+/// keeping the section's style. Characters missing from every font stay in
+/// the section's font and render as tofu.
+///
+/// The unsplit sections are stored as the source. Writing to the first section of
+/// a split section replaces that source section, other writes are joined back into it.
+/// Truncation by [`MaxLines`] does not modify the source.
+#[derive(Debug, Clone, Default, Component, Reflect)]
+pub struct FontFallback {
+    pub fonts: Vec<Handle<Font>>,
+    source: Vec<TextSection>,
+    /// Index into `source` of each split section.
+    origin: Vec<usize>,
+    cache: Vec<String>,
+    dirty: bool,
+}
+
+impl FontFallback {
+    pub fn new(fonts: impl IntoIterator<Item = Handle<Font>>) -> Self {
+        FontFallback {
+            fonts: fonts.into_iter().collect(),
+            dirty: true,
+            ..Default::default()
+        }
+    }
+
+    /// Update the source from sections written over `reference`, the last known values.
+    fn update_source(&mut self, sections: &[TextSection], reference: &[String]) {
+        if sections.len() != reference.len() || self.origin.len() < sections.len() {
+            self.source = sections.to_vec();
+            return;
+        }
+        for (index, source) in self.source.iter_mut().enumerate() {
+            let group: Vec<_> = (0..sections.len())
+                .filter(|i| self.origin[*i] == index)
+                .collect();
+            // Removed by `MaxLines`.
+            let Some(&first) = group.first() else {continue};
+            let unchanged = |i: &usize| sections[*i].value == reference[*i];
+            if group.iter().all(unchanged) {
+                continue;
+            }
+            source.value = if group[1..].iter().all(unchanged) {
+                sections[first].value.clone()
+            } else {
+                group.iter().map(|i| sections[*i].value.as_str()).collect()
+            };
+            source.style = TextStyle {
+                font: source.style.font.clone(),
+                ..sections[first].style.clone()
+            };
+        }
+    }
+}
+
+pub fn font_fallback_system(
+    fonts: Res<Assets<Font>>,
+    mut query: Query<(&mut FontFallback, &mut Text, Option<&MaxLines>)>,
+) {
+    for (mut fallback, mut text, max_lines) in query.iter_mut() {
+        if !text.is_changed() && !fallback.dirty {
+            continue;
+        }
+        let fallback = fallback.as_mut();
+        // `MaxLines` runs after this system and owns the last write if present.
+        let reference = match max_lines {
+            Some(max_lines) => &max_lines.cache,
+            None => &fallback.cache,
+        };
+        let written = !text.sections.iter().map(|x| &x.value).eq(reference.iter());
+        if written {
+            let reference = reference.clone();
+            fallback.update_source(&text.sections, &reference);
+        } else if !fallback.dirty {
+            continue;
+        }
+        let mut sections: Vec<TextSection> = Vec::new();
+        let mut origin = Vec::new();
+        let mut loaded = true;
+        for (index, source) in fallback.source.iter().enumerate() {
+            let primary = &source.style.font;
+            let chain: Option<Vec<_>> = std::iter::once(primary)
+                .chain(fallback.fonts.iter())
+                .map(|x| fonts.get(x).map(|f| (x, f)))
+                .collect();
+            let Some(chain) = chain else {
+                loaded = false;
+                break;
+            };
+            let start = sections.len();
+            for c in source.value.chars() {
+                // Whitespace continues the current run.
+                if let (true, Some(last)) = (c.is_whitespace(), sections[start..].last_mut()) {
+                    last.value.push(c);
+                    continue;
+                }
+                let font = chain.iter()
+                    .find(|(_, f)| f.font.glyph_id(c).0 != 0)
+                    .map(|(h, _)| *h)
+                    .unwrap_or(primary);
+                match sections[start..].last_mut() {
+                    Some(last) if &last.style.font == font => last.value.push(c),
+                    _ => sections.push(TextSection {
+                        value: c.to_string(),
+                        style: TextStyle {
+                            font: font.clone(),
+                            ..source.style.clone()
+                        }
+                    }),
+                }
+            }
+            if sections.len() == start {
+                sections.push(source.clone());
+            }
+            origin.resize(sections.len(), index);
+        }
+        // Wait until every font in the chain is loaded.
+        if !loaded {
+            fallback.dirty = true;
+            continue;
+        }
+        fallback.cache = sections.iter().map(|x| x.value.clone()).collect();
+        fallback.origin = origin;
+        fallback.dirty = false;
+        text.sections = sections;
+    }
+}
+
+/// Truncate a [`Text`] to at most `lines` rendered lines, ending with an ellipsis.
+/// `lines` is at least `1`.
+///
+/// Line breaks are read from [`TextLayoutInfo`] of the last frame,
+/// so truncation takes effect after one or more frames.
+/// The original text is restored and truncated again when [`Text2dBounds`] changes.
+///
+/// A word longer than a line is truncated at a glyph, not a word boundary.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+pub struct MaxLines {
+    pub lines: usize,
+    pub ellipsis: String,
+    source: Vec<String>,
+    cache: Vec<String>,
+}
+
+impl MaxLines {
+    pub fn new(lines: usize) -> Self {
+        MaxLines {
+            lines,
+            ellipsis: "\u{2026}".to_owned(),
+            source: Vec::new(),
+            cache: Vec::new(),
+        }
+    }
+
+    pub fn with_ellipsis(mut self, ellipsis: impl Into<String>) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+}
+
+impl Default for MaxLines {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+pub fn max_lines_system(
+    mut query: Query<(&mut MaxLines, &mut Text, Ref<TextLayoutInfo>, Ref<Text2dBounds>)>,
+) {
+    for (mut max_lines, mut text, layout, bounds) in query.iter_mut() {
+        let values = text.sections.iter().map(|x| &x.value);
+        if !values.eq(max_lines.cache.iter()) {
+            // Text changed externally.
+            max_lines.source = text.sections.iter().map(|x| x.value.clone()).collect();
+            max_lines.cache = max_lines.source.clone();
+            continue;
+        }
+        if bounds.is_changed() && max_lines.source.len() == text.sections.len() {
+            for (section, source) in text.sections.iter_mut().zip(max_lines.source.iter()) {
+                if &section.value != source {
+                    section.value.clone_from(source);
+                }
+            }
+            max_lines.cache = max_lines.source.clone();
+            continue;
+        }
+        if !layout.is_changed() {
+            continue;
+        }
+        // Find the first glyph past the last allowed line, a line starts when `x` goes backwards.
+        let mut line = 1;
+        let mut last_x = f32::MIN;
+        let mut cutoff = None;
+        for glyph in &layout.glyphs {
+            if glyph.position.x < last_x {
+                line += 1;
+            }
+            last_x = glyph.position.x;
+            if line > max_lines.lines.max(1) {
+                cutoff = Some((glyph.section_index, glyph.byte_index));
+                break;
+            }
+        }
+        let Some((section_index, byte_index)) = cutoff else {continue};
+        let ellipsis = max_lines.ellipsis.clone();
+        text.sections.truncate(section_index + 1);
+        if let Some(section) = text.sections.get_mut(section_index) {
+            section.value.truncate(byte_index);
+        }
+        // Remove one more character to make room for the ellipsis.
+        let mut removed = false;
+        while let Some(section) = text.sections.last_mut() {
+            if !removed {
+                removed = section.value.pop().is_some();
+            }
+            let len = section.value.trim_end().len();
+            section.value.truncate(len);
+            if (removed && !section.value.is_empty()) || text.sections.len() == 1 {
+                break;
+            }
+            text.sections.pop();
+        }
+        if let Some(section) = text.sections.last_mut() {
+            section.value.push_str(&ellipsis);
+        }
+        max_lines.cache = text.sections.iter().map(|x| x.value.clone()).collect();
+    }
+}
+
 pub fn sync_em_text_fragment(
     mut query: Query<(&DimensionData, &mut TextFragment), Changed<TextFragment>, >
 ) {
@@ -150,3 +389,317 @@ pub fn sync_sprite_text_fragment(
         }, TextureDimension::D2, buffer, TextureFormat::Rgba8Unorm, RenderAssetUsages::all())
     }
 }
+
+/// Renders an outline behind a [`Text`] for legibility.
+///
+/// Implemented as offset copies of the text spawned as children,
+/// which follow the text's content, font size and opacity.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct TextOutline {
+    /// Color of the outline, alpha is multiplied by the text's opacity.
+    pub color: Color,
+    /// Width of the outline in pixels.
+    pub width: f32,
+    /// If set, renders in 8 directions instead of 4.
+    pub diagonal: bool,
+}
+
+impl TextOutline {
+    pub const fn new(color: Color, width: f32) -> Self {
+        Self { color, width, diagonal: false }
+    }
+
+    /// Render in 8 directions instead of 4, smoother but more expensive.
+    pub const fn with_diagonal(mut self) -> Self {
+        self.diagonal = true;
+        self
+    }
+
+    /// Offsets of the copies of text.
+    pub fn offsets(&self) -> Vec<Vec2> {
+        const CARDINAL: [Vec2; 4] = [Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y];
+        const DIAGONAL: [Vec2; 4] = [
+            Vec2::new(1.0, 1.0), Vec2::new(-1.0, 1.0),
+            Vec2::new(1.0, -1.0), Vec2::new(-1.0, -1.0),
+        ];
+        let mut result: Vec<_> = CARDINAL.iter().map(|x| *x * self.width).collect();
+        if self.diagonal {
+            result.extend(DIAGONAL.iter().map(|x| x.normalize() * self.width));
+        }
+        result
+    }
+}
+
+impl Default for TextOutline {
+    fn default() -> Self {
+        Self::new(Color::BLACK, 1.0)
+    }
+}
+
+/// Marker for a copy of text spawned by [`TextOutline`].
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct TextOutlineCopy;
+
+pub fn sync_text_outline(
+    mut commands: Commands,
+    query: Query<(Entity, &TextOutline, &Text, &Text2dBounds, &Transform2D, &Opacity, Option<&Children>)>,
+    mut copies: Query<(&mut Text, &mut Text2dBounds, &mut Transform2D),
+        (With<TextOutlineCopy>, Without<TextOutline>)>,
+) {
+    for (entity, outline, text, bounds, transform, opacity, children) in query.iter() {
+        let offsets = outline.offsets();
+        let color = outline.color.with_a(outline.color.a() * opacity.get());
+        let outline_text = Text {
+            sections: text.sections.iter().map(|section| {
+                let mut section = section.clone();
+                section.style.color = color;
+                section
+            }).collect(),
+            ..text.clone()
+        };
+        let existing: Vec<_> = children.into_iter()
+            .flatten()
+            .filter(|x| copies.contains(**x))
+            .copied()
+            .collect();
+        if existing.len() != offsets.len() {
+            for copy in existing {
+                commands.entity(copy).despawn_recursive();
+            }
+            for offset in offsets {
+                let copy = commands.spawn((
+                    RectrayBundle {
+                        transform: Transform2D {
+                            anchor: transform.anchor,
+                            parent_anchor: transform.anchor,
+                            offset: Size2::pixels(offset.x, offset.y),
+                            z: -0.001,
+                            ..Transform2D::UNIT
+                        },
+                        ..Default::default()
+                    },
+                    outline_text.clone(),
+                    *bounds,
+                    TextLayoutInfo::default(),
+                    bevy::sprite::Anchor::default(),
+                    BuildTransformBundle::default(),
+                    OptOutFontSizeSync,
+                    OptOutTextBoundsSync,
+                    TextOutlineCopy,
+                )).id();
+                commands.entity(entity).add_child(copy);
+            }
+            continue;
+        }
+        for (copy, offset) in existing.into_iter().zip(offsets) {
+            let Ok((mut copy_text, mut copy_bounds, mut copy_transform)) = copies.get_mut(copy) else {continue};
+            if !sections_eq(&copy_text, &outline_text)
+                    || copy_text.justify != outline_text.justify
+                    || copy_text.linebreak_behavior != outline_text.linebreak_behavior {
+                *copy_text = outline_text.clone();
+            }
+            if copy_bounds.size != bounds.size {
+                *copy_bounds = *bounds;
+            }
+            let offset = Size2::pixels(offset.x, offset.y);
+            if copy_transform.anchor != transform.anchor || copy_transform.offset != offset {
+                copy_transform.anchor = transform.anchor;
+                copy_transform.parent_anchor = transform.anchor;
+                copy_transform.offset = offset;
+            }
+        }
+    }
+}
+
+/// Renders rectangles behind ranges of a [`Text`], i.e. search results.
+///
+/// Ranges are byte ranges into the text, with sections concatenated.
+/// Ranges spanning multiple lines render one rectangle per line.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+pub struct TextHighlight {
+    pub ranges: Vec<Range<usize>>,
+    /// Color of the rectangles, alpha is multiplied by the text's opacity.
+    pub color: Color,
+}
+
+impl TextHighlight {
+    pub fn new(color: Color, ranges: impl IntoIterator<Item = Range<usize>>) -> Self {
+        Self { ranges: ranges.into_iter().collect(), color }
+    }
+
+    /// Returns true if a byte is highlighted.
+    pub fn contains(&self, index: usize) -> bool {
+        self.ranges.iter().any(|x| x.contains(&index))
+    }
+}
+
+/// Marker for a rectangle spawned by [`TextHighlight`].
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct TextHighlightRect;
+
+pub fn sync_text_highlight(
+    mut commands: Commands,
+    query: Query<(Entity, &TextHighlight, &Text, &TextLayoutInfo, &Transform2D, &DimensionData, Option<&Children>),
+        Or<(Changed<TextHighlight>, Changed<TextLayoutInfo>, Changed<DimensionData>)>>,
+    rects: Query<(), With<TextHighlightRect>>,
+) {
+    for (entity, highlight, text, layout, transform, dimension, children) in query.iter() {
+        for child in children.into_iter().flatten() {
+            if rects.contains(*child) {
+                commands.entity(*child).despawn_recursive();
+            }
+        }
+        let starts: Vec<_> = text.sections.iter()
+            .scan(0, |acc, section| {
+                let start = *acc;
+                *acc += section.value.len();
+                Some(start)
+            })
+            .collect();
+        // Min and max of each highlighted run of glyphs on a line.
+        let mut segments: Vec<(Vec2, Vec2)> = Vec::new();
+        let mut current: Option<(Vec2, Vec2)> = None;
+        for glyph in &layout.glyphs {
+            let index = starts.get(glyph.section_index).copied().unwrap_or(0) + glyph.byte_index;
+            let min = glyph.position - glyph.size / 2.0;
+            let max = glyph.position + glyph.size / 2.0;
+            current = match current {
+                _ if !highlight.contains(index) => {
+                    segments.extend(current);
+                    None
+                },
+                // Started a new line.
+                Some((cmin, cmax)) if min.x < cmin.x => {
+                    segments.push((cmin, cmax));
+                    Some((min, max))
+                },
+                Some((cmin, cmax)) => Some((cmin.min(min), cmax.max(max))),
+                None => Some((min, max)),
+            }
+        }
+        segments.extend(current);
+        // Glyph positions start from the bottom left of the text.
+        let anchor = transform.anchor.or(crate::Anchor::CENTER).as_vec();
+        let origin = anchor * (dimension.size - layout.logical_size) - layout.logical_size / 2.0;
+        for (min, max) in segments {
+            let center = origin + (min + max) / 2.0;
+            let size = max - min;
+            let rect = commands.spawn((
+                RectrayBundle {
+                    transform: Transform2D {
+                        offset: Size2::pixels(center.x, center.y),
+                        z: -0.002,
+                        ..Transform2D::UNIT
+                    },
+                    dimension: Dimension::owned(Size2::pixels(size.x, size.y)),
+                    ..Default::default()
+                },
+                Sprite::default(),
+                Handle::<Image>::default(),
+                Coloring::new(highlight.color),
+                BuildTransformBundle::default(),
+                TextHighlightRect,
+            )).id();
+            commands.entity(entity).add_child(rect);
+        }
+    }
+}
+
+/// Despawn rectangles if [`TextHighlight`] is removed.
+pub fn remove_text_highlight(
+    mut commands: Commands,
+    mut removed: RemovedComponents<TextHighlight>,
+    rects: Query<(Entity, &Parent), With<TextHighlightRect>>,
+) {
+    let removed: Vec<_> = removed.read().collect();
+    if removed.is_empty() {
+        return;
+    }
+    for (entity, parent) in rects.iter() {
+        if removed.contains(&parent.get()) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn sections_eq(a: &Text, b: &Text) -> bool {
+    a.sections.len() == b.sections.len() && a.sections.iter().zip(b.sections.iter()).all(|(a, b)|
+        a.value == b.value
+            && a.style.font == b.style.font
+            && a.style.font_size == b.style.font_size
+            && a.style.color == b.style.color
+    )
+}
+
+/// Despawn copies of text if [`TextOutline`] is removed.
+pub fn remove_text_outline(
+    mut commands: Commands,
+    mut removed: RemovedComponents<TextOutline>,
+    copies: Query<(Entity, &Parent), With<TextOutlineCopy>>,
+) {
+    let removed: Vec<_> = removed.read().collect();
+    if removed.is_empty() {
+        return;
+    }
+    for (entity, parent) in copies.iter() {
+        if removed.contains(&parent.get()) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::{system::{IntoSystem, System}, world::World};
+    use bevy::math::Vec2;
+    use bevy::text::{GlyphAtlasInfo, PositionedGlyph, Text, Text2dBounds, TextLayoutInfo, TextStyle};
+    use super::{max_lines_system, MaxLines};
+
+    fn glyph(x: f32, y: f32, byte_index: usize) -> PositionedGlyph {
+        PositionedGlyph {
+            position: Vec2::new(x, y),
+            size: Vec2::ONE,
+            atlas_info: GlyphAtlasInfo {
+                texture_atlas: Default::default(),
+                texture: Default::default(),
+                glyph_index: 0,
+            },
+            section_index: 0,
+            byte_index,
+        }
+    }
+
+    #[test]
+    fn truncate_and_restore() {
+        let mut world = World::new();
+        let mut system = IntoSystem::into_system(max_lines_system);
+        system.initialize(&mut world);
+        let entity = world.spawn((
+            MaxLines::new(1),
+            Text::from_section("ab cd", TextStyle::default()),
+            TextLayoutInfo::default(),
+            Text2dBounds::default(),
+        )).id();
+        // Records the source text.
+        system.run((), &mut world);
+
+        world.get_mut::<TextLayoutInfo>(entity).unwrap().glyphs = vec![
+            glyph(0.0, 0.0, 0),
+            glyph(1.0, 0.0, 1),
+            glyph(0.0, -1.0, 3),
+            glyph(1.0, -1.0, 4),
+        ];
+        system.run((), &mut world);
+        assert_eq!(world.get::<Text>(entity).unwrap().sections[0].value, "ab\u{2026}");
+
+        // Truncated text is not treated as an external change.
+        world.get_mut::<TextLayoutInfo>(entity).unwrap().glyphs.truncate(2);
+        system.run((), &mut world);
+        assert_eq!(world.get::<Text>(entity).unwrap().sections[0].value, "ab\u{2026}");
+
+        // Resizing restores the source text to be measured again.
+        world.get_mut::<Text2dBounds>(entity).unwrap().size = Vec2::new(100.0, 100.0);
+        system.run((), &mut world);
+        assert_eq!(world.get::<Text>(entity).unwrap().sections[0].value, "ab cd");
+    }
+}
@@ -1,16 +1,23 @@
+use std::any::TypeId;
 use std::mem;
+use std::marker::PhantomData;
 
-use bevy::{ecs::{query::{With, Without}, entity::Entity, system::{Commands, Query, Res, Resource}, component::Component}, hierarchy::Children, window::{PrimaryWindow, Window, CursorIcon}, reflect::Reflect};
+use bevy::{ecs::{change_detection::DetectChanges, query::{Has, Or, With, Without}, entity::Entity, system::{Commands, Query, Res, Resource}, component::Component}, time::Time, hierarchy::{Children, DespawnRecursiveExt}, window::{PrimaryWindow, Window, CursorIcon}, reflect::Reflect, render::view::InheritedVisibility};
+use bevy_defer::signals::{SignalId, SignalReceiver};
 
-use crate::{anim::VisibilityToggle, dsl::prelude::EventFlags, events::CursorFocus};
+use crate::{anim::{Easing, Interpolate, Interpolation, VisibilityToggle}, dsl::prelude::EventFlags, events::{CursorFocus, CursorState}, Clipping, Dimension, FadeDisabled, Opacity, RotatedRect, SpriteFit};
 
 use super::button::CheckButtonState;
+use super::misc::BadgeState;
 
 
 /// Set the window's [cursor](bevy::window::Window::cursor) value
 /// if the sprite has obtained [`CursorFocus`]
 /// and the `CursorFocus` is some [`EventFlags`].
 ///
+/// If multiple sprites match, the top-most wins, see [`CursorPriority`].
+/// If none match, the cursor is set to [`CursorDefault`].
+///
 /// Try remove the [`CursorDefault`] resource
 /// if you want to have more control over cursor logic.
 #[derive(Debug, Clone, Copy, Component, Reflect)]
@@ -19,17 +26,208 @@ pub struct SetCursor {
     pub icon: CursorIcon,
 }
 
+/// Override the icon of [`SetCursor`] while this entity's state `T` equals `state`,
+/// i.e. a locked toggle showing `CursorIcon::NotAllowed`.
+///
+/// Registered for [`CheckButtonState`], use `register_set_cursor_if` for other components.
+///
+/// Multiple `SetCursorIf` with different `T` can be used on the same entity,
+/// if more than one matches, the state that matched first is used.
+///
+/// Disabled sprites with [`SetCursor`] always show `CursorIcon::NotAllowed` when hovered.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct SetCursorIf<T> {
+    pub state: T,
+    pub icon: CursorIcon,
+}
+
+impl<T> SetCursorIf<T> {
+    pub const fn new(state: T, icon: CursorIcon) -> Self {
+        Self { state, icon }
+    }
+}
+
+/// Icons set by [`SetCursorIf`], keyed by the state type.
+///
+/// If multiple states match, the one that matched first is used.
+#[derive(Debug, Clone, Component, Default)]
+pub(crate) struct CursorIconOverride(Vec<(TypeId, CursorIcon)>);
+
+impl CursorIconOverride {
+    pub(crate) fn get(&self) -> Option<CursorIcon> {
+        self.0.first().map(|(_, icon)| *icon)
+    }
+}
+
+pub(crate) fn set_cursor_if<T: Component + PartialEq>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &SetCursorIf<T>, Option<&T>, Option<&mut CursorIconOverride>)>,
+) {
+    let key = TypeId::of::<T>();
+    for (entity, cond, state, icon_override) in query.iter_mut() {
+        let icon = (state == Some(&cond.state)).then_some(cond.icon);
+        match (icon_override, icon) {
+            (Some(mut icon_override), Some(icon)) => {
+                match icon_override.0.iter().position(|(ty, _)| *ty == key) {
+                    Some(idx) => if icon_override.0[idx].1 != icon {
+                        icon_override.0[idx].1 = icon;
+                    },
+                    None => icon_override.0.push((key, icon)),
+                }
+            },
+            (Some(mut icon_override), None) => {
+                if icon_override.0.iter().any(|(ty, _)| *ty == key) {
+                    icon_override.0.retain(|(ty, _)| *ty != key);
+                }
+            },
+            (None, Some(icon)) => {
+                commands.entity(entity).insert(CursorIconOverride(vec![(key, icon)]));
+            },
+            (None, None) => (),
+        }
+    }
+}
+
 /// Visible only when some conditions are met.
 ///
 /// Supported conditions are:
 ///
 /// * `EventFlags`: For `CursorFocus`
 /// * `CheckButtonState`: For `CheckButton` and `RadioButton`'s status
+/// * `BadgeState`: For whether a `Badge`'s count is zero
 ///
 /// This component uses `Interpolate<Opacity>` if exists, if not, uses `Visibility`.
+/// Add [`DisplayIfFade`] to fade in and out.
 #[derive(Debug, Clone, Copy, Component, Default, Reflect)]
 pub struct DisplayIf<T>(pub T);
 
+/// Fade [`DisplayIf`] in and out instead of toggling visibility.
+///
+/// Inserts an `Interpolate<Opacity>` if not present.
+/// Hit-testing is disabled while the entity is fading out or hidden,
+/// through [`FadeDisabled`] instead of [`Opacity::disabled`].
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct DisplayIfFade {
+    /// Duration of the fade in seconds.
+    pub time: f32,
+    /// Easing of the fade.
+    #[reflect(ignore)]
+    pub easing: Easing,
+}
+
+impl DisplayIfFade {
+    pub const fn new(time: f32) -> Self {
+        Self { time, easing: Easing::Linear }
+    }
+
+    pub const fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+impl Default for DisplayIfFade {
+    fn default() -> Self {
+        Self::new(0.15)
+    }
+}
+
+/// Recursively despawn this entity when signal `T` is received.
+///
+/// Registered for `ButtonClick` and `Invocation`,
+/// use `register_despawn_on_signal` for other signals.
+#[derive(Debug, Component)]
+pub struct DespawnOnSignal<T: SignalId>(PhantomData<T>);
+
+impl<T: SignalId> DespawnOnSignal<T> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: SignalId> Default for DespawnOnSignal<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn despawn_on_signal<T: SignalId>(
+    mut commands: Commands,
+    mut query: Query<(Entity, SignalReceiver<T>), With<DespawnOnSignal<T>>>,
+) {
+    for (entity, recv) in query.iter_mut() {
+        if recv.poll_once().is_some() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Enable or disable a widget by setting [`Opacity::disabled`] when a `bool` is received.
+///
+/// `enabled` is applied when inserted, so this also determines the state before the first signal.
+/// Default is disabled until `true` is received.
+///
+/// Can be combined with [`DisplayIfFade`], which disables through [`FadeDisabled`] instead.
+///
+/// When used as a signal id, receives whether the widget is enabled.
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+pub struct EnabledIf {
+    pub enabled: bool,
+}
+
+impl EnabledIf {
+    pub const fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl SignalId for EnabledIf {
+    type Data = bool;
+}
+
+pub(crate) fn enabled_if(
+    mut query: Query<(&mut EnabledIf, SignalReceiver<EnabledIf>, &mut Opacity)>,
+) {
+    for (mut enabled, recv, mut opacity) in query.iter_mut() {
+        let value = match recv.poll_once() {
+            Some(value) => {
+                enabled.enabled = value;
+                value
+            },
+            None if enabled.is_changed() => enabled.enabled,
+            None => continue,
+        };
+        if opacity.disabled == value {
+            opacity.disabled = !value;
+        }
+    }
+}
+
+pub(crate) fn display_if_fade_init(
+    mut commands: Commands,
+    query: Query<(Entity, &DisplayIfFade, &Opacity, Has<Interpolate<Opacity>>),
+        Or<(Without<Interpolate<Opacity>>, Without<FadeDisabled>)>>,
+) {
+    for (entity, fade, opacity, has_interpolate) in query.iter() {
+        let mut entity = commands.entity(entity);
+        entity.insert(FadeDisabled::default());
+        if !has_interpolate {
+            entity.insert(Interpolate::<Opacity>::new(fade.easing, opacity.opacity, fade.time));
+        }
+    }
+}
+
+pub(crate) fn display_if_fade_hit_test(
+    mut query: Query<(&mut FadeDisabled, &Interpolate<Opacity>), With<DisplayIfFade>>,
+) {
+    for (mut fade, interpolate) in query.iter_mut() {
+        let disabled = interpolate.target() <= 0.0 || interpolate.get() <= 0.0;
+        if fade.0 != disabled {
+            fade.0 = disabled;
+        }
+    }
+}
+
 pub(crate) fn event_conditional_visibility(mut query: Query<(&DisplayIf<EventFlags>, Option<&CursorFocus>, VisibilityToggle)>){
     query.iter_mut().for_each(|(display_if, focus, mut vis)| {
         if focus.is_some() && display_if.0.contains(focus.unwrap().flags())
@@ -53,6 +251,168 @@ pub(crate) fn check_conditional_visibility(
     })
 }
 
+pub(crate) fn badge_conditional_visibility(
+    mut query: Query<(&DisplayIf<BadgeState>, &BadgeState, VisibilityToggle)>
+) {
+    query.iter_mut().for_each(|(display_if, state, mut vis)| {
+        if &display_if.0 == state {
+            vis.set_visible(true)
+        } else {
+            vis.set_visible(false)
+        }
+    })
+}
+
+/// Expand the width of a sidebar while hovered, for example a collapsed icon bar.
+///
+/// Requires `Interpolate<Dimension>` and `EventFlags::Hover`, widths are in pixels.
+/// The sidebar is also considered hovered if the cursor is over one of its children.
+///
+/// Sets [`CheckButtonState::Checked`] while expanded, add [`PropagateFocus`]
+/// and use `DisplayIf(CheckButtonState::Checked)` to reveal labels.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct HoverExpand {
+    /// Width while collapsed.
+    pub collapsed: f32,
+    /// Width while expanded.
+    pub expanded: f32,
+    /// Seconds to wait after the cursor leaves before collapsing.
+    pub delay: f32,
+    timer: f32,
+}
+
+impl HoverExpand {
+    pub const fn new(collapsed: f32, expanded: f32) -> Self {
+        Self { collapsed, expanded, delay: 0.15, timer: 0.0 }
+    }
+
+    pub const fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns true if expanded or waiting to collapse.
+    pub fn is_expanded(&self) -> bool {
+        self.timer > 0.0
+    }
+}
+
+pub(crate) fn hover_expand(
+    time: Res<Time>,
+    state: Res<CursorState>,
+    mut query: Query<(&mut HoverExpand, &RotatedRect, Option<&CursorFocus>, &mut Interpolate<Dimension>)>,
+) {
+    let cursor = state.world_position();
+    for (mut expand, rect, focus, mut interpolate) in query.iter_mut() {
+        let hovered = focus.is_some_and(|x| x.intersects(EventFlags::Hover | EventFlags::AnyDown))
+            || (state.in_window() && rect.local_space(cursor).abs().cmple(rect.half_dim()).all());
+        if hovered {
+            expand.timer = expand.delay.max(f32::EPSILON);
+        } else if expand.timer > 0.0 {
+            expand.timer -= time.delta_seconds();
+        }
+        let expanded = expand.is_expanded();
+        interpolate.interpolate_to_x(if expanded { expand.expanded } else { expand.collapsed });
+    }
+}
+
+/// Cross-fade between two sprites by opacity, i.e. two skins of a character
+/// built from different atlases.
+///
+/// `a` has opacity `1 - t` and `b` has opacity `t`. If a sprite has an `Interpolate<Opacity>`
+/// it fades to the new value, otherwise its opacity is set directly.
+///
+/// `fit` is written to both sprites so images with different aspect ratios
+/// line up, both sprites should usually have the same dimension.
+///
+/// When used as a signal id, receives a new value for `t`.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+pub struct CrossFadeSprite {
+    pub a: Entity,
+    pub b: Entity,
+    /// Progress from `a` to `b`, in `0..=1`.
+    pub t: f32,
+    pub fit: SpriteFit,
+}
+
+impl CrossFadeSprite {
+    pub const fn new(a: Entity, b: Entity) -> Self {
+        Self { a, b, t: 0.0, fit: SpriteFit::Contain }
+    }
+
+    pub const fn with_fit(mut self, fit: SpriteFit) -> Self {
+        self.fit = fit;
+        self
+    }
+}
+
+impl SignalId for CrossFadeSprite {
+    type Data = f32;
+}
+
+pub(crate) fn cross_fade_sprite(
+    mut commands: Commands,
+    mut query: Query<(&mut CrossFadeSprite, SignalReceiver<CrossFadeSprite>)>,
+    mut sprites: Query<(&mut Opacity, Option<&mut Interpolate<Opacity>>, Option<&SpriteFit>)>,
+) {
+    for (mut fade, recv) in query.iter_mut() {
+        if let Some(t) = recv.poll_once() {
+            fade.t = t;
+        }
+        if !fade.is_changed() {
+            continue;
+        }
+        let t = fade.t.clamp(0.0, 1.0);
+        for (entity, value) in [(fade.a, 1.0 - t), (fade.b, t)] {
+            let Ok((mut opacity, interpolate, fit)) = sprites.get_mut(entity) else { continue };
+            match interpolate {
+                Some(mut interpolate) => interpolate.interpolate_to(value),
+                None => if opacity.opacity != value {
+                    opacity.opacity = value;
+                },
+            }
+            if fit != Some(&fade.fit) {
+                commands.entity(entity).insert(fade.fit);
+            }
+        }
+    }
+}
+
+/// Interaction state of a widget derived from [`CursorFocus`], [`CheckButtonState`]
+/// and [`Opacity::is_disabled`], for styling systems.
+///
+/// Updated at the end of `PreUpdate` once focus has propagated,
+/// so it is up to date for every system in `Update`.
+/// Only written when changed, so `Changed<WidgetState>` can be used.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component, Reflect)]
+pub struct WidgetState {
+    /// Cursor is hovering or pressing the widget.
+    pub hovered: bool,
+    /// Cursor is pressing or dragging the widget.
+    pub pressed: bool,
+    /// Widget or one of its ancestors is disabled.
+    pub disabled: bool,
+    /// Widget is a checked `CheckButton` or `RadioButton`.
+    pub checked: bool,
+}
+
+pub(crate) fn widget_state_system(
+    mut query: Query<(&mut WidgetState, Option<&CursorFocus>, Option<&CheckButtonState>, Option<&Opacity>)>,
+) {
+    let pressed = EventFlags::LeftPressed | EventFlags::MidPressed | EventFlags::RightPressed | EventFlags::AnyDrag;
+    for (mut state, focus, check, opacity) in query.iter_mut() {
+        let value = WidgetState {
+            hovered: focus.is_some_and(|x| x.intersects(EventFlags::Hover | pressed)),
+            pressed: focus.is_some_and(|x| x.intersects(pressed)),
+            disabled: opacity.is_some_and(|x| x.is_disabled()),
+            checked: check == Some(&CheckButtonState::Checked),
+        };
+        if *state != value {
+            *state = value;
+        }
+    }
+}
+
 /// If set, we set the cursor to a default value every frame.
 ///
 /// Remove this if custom behavior is desired.
@@ -65,18 +425,45 @@ impl Default for CursorDefault {
     }
 }
 
+/// Priority of [`SetCursor`] when multiple sprites set the cursor.
+///
+/// Higher priority wins, ties are resolved by the top-most sprite. Default is `0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Component, Reflect)]
+pub struct CursorPriority(pub i32);
+
 pub(crate) fn set_cursor(
     default_cursor: Option<Res<CursorDefault>>,
+    state: Res<CursorState>,
     mut window: Query<&mut Window, With<PrimaryWindow>>,
-    query: Query<(&SetCursor, &CursorFocus)>,
+    query: Query<(&SetCursor, Option<&CursorFocus>, Option<&CursorPriority>, Option<&RotatedRect>,
+        Option<&Opacity>, Option<&CursorIconOverride>, Option<&Clipping>, Option<&InheritedVisibility>)>,
 ){
-    for (cursor, focus) in query.iter() {
-        if cursor.flags.contains(focus.flags()) {
-            if let Ok(mut window) = window.get_single_mut() {
-                window.cursor.icon = cursor.icon;
+    let position = state.world_position();
+    let cursor = query.iter()
+        .filter_map(|(cursor, focus, priority, rect, opacity, icon_override, clipping, vis)| {
+            // Disabled sprites do not receive `CursorFocus`.
+            if let Some(opacity) = opacity.filter(|x| x.is_disabled()) {
+                // `RotatedRect` is stale if hidden or occluded.
+                let visible = opacity.get() > 0.0 && vis.map_or(true, |x| x.get());
+                let hovered = visible && state.in_window()
+                    && clipping.map_or(true, |x| x.contains(position))
+                    && rect.is_some_and(|x| x.local_space(position).abs().cmple(x.half_dim()).all());
+                return hovered.then_some((CursorIcon::NotAllowed, priority, rect));
+            }
+            if !cursor.flags.contains(focus?.flags()) {
+                return None;
             }
-            return;
+            Some((icon_override.and_then(|x| x.get()).unwrap_or(cursor.icon), priority, rect))
+        })
+        .max_by(|(_, a, ra), (_, b, rb)| a.copied().unwrap_or_default()
+            .cmp(&b.copied().unwrap_or_default())
+            .then_with(|| ra.map(|x| x.z).unwrap_or(0.0).total_cmp(&rb.map(|x| x.z).unwrap_or(0.0)))
+        );
+    if let Some((icon, ..)) = cursor {
+        if let Ok(mut window) = window.get_single_mut() {
+            window.cursor.icon = icon;
         }
+        return;
     }
     if let Some(icon) = default_cursor{
         if let Ok(mut window) = window.get_single_mut() {
@@ -144,3 +531,122 @@ impl<T> OptionDo<T> for Option<T> {
         }
     }
 }
+
+/// Drive `Interpolate<T>` to a target value based on `CursorFocus` and `Opacity::computed_disabled`.
+///
+/// Missing states fall back to `idle`, disabled takes precedence over pressed,
+/// and pressed takes precedence over hover.
+///
+/// Requires `Interpolate<T>` on the same entity.
+#[derive(Component)]
+pub struct CursorStateAnim<T: Interpolation> {
+    pub idle: T::FrontEnd,
+    pub hover: Option<T::FrontEnd>,
+    pub pressed: Option<T::FrontEnd>,
+    pub disabled: Option<T::FrontEnd>,
+}
+
+impl<T: Interpolation> Clone for CursorStateAnim<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Interpolation> Copy for CursorStateAnim<T> {}
+
+impl<T: Interpolation> std::fmt::Debug for CursorStateAnim<T> where T::FrontEnd: std::fmt::Debug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CursorStateAnim")
+            .field("idle", &self.idle)
+            .field("hover", &self.hover)
+            .field("pressed", &self.pressed)
+            .field("disabled", &self.disabled)
+            .finish()
+    }
+}
+
+impl<T: Interpolation> CursorStateAnim<T> {
+    pub fn new(idle: T::FrontEnd) -> Self {
+        Self {
+            idle,
+            hover: None,
+            pressed: None,
+            disabled: None,
+        }
+    }
+
+    pub fn with_hover(mut self, hover: T::FrontEnd) -> Self {
+        self.hover = Some(hover);
+        self
+    }
+
+    pub fn with_pressed(mut self, pressed: T::FrontEnd) -> Self {
+        self.pressed = Some(pressed);
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: T::FrontEnd) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
+    /// Obtain the target value of a state.
+    pub fn get(&self, focus: Option<&CursorFocus>, disabled: bool) -> T::FrontEnd {
+        if disabled {
+            return self.disabled.unwrap_or(self.idle);
+        }
+        match focus {
+            Some(focus) if focus.intersects(EventFlags::LeftPressed) => self.pressed.unwrap_or(self.idle),
+            Some(focus) if focus.intersects(EventFlags::Hover) => self.hover.unwrap_or(self.idle),
+            _ => self.idle,
+        }
+    }
+}
+
+pub fn cursor_state_anim<T: Interpolation>(
+    mut query: Query<(&CursorStateAnim<T>, Option<&CursorFocus>, &Opacity, &mut Interpolate<T>)>,
+) {
+    for (anim, focus, opacity, mut interpolate) in query.iter_mut() {
+        let target = anim.get(focus, opacity.computed_disabled);
+        if interpolate.target() != target {
+            interpolate.interpolate_to(target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::ecs::{system::{IntoSystem, System}, world::World};
+    use crate::{Opacity, SpriteFit};
+    use super::{cross_fade_sprite, CrossFadeSprite};
+
+    #[test]
+    fn cross_fade_on_change() {
+        let mut world = World::new();
+        let mut system = IntoSystem::into_system(cross_fade_sprite);
+        system.initialize(&mut world);
+        let a = world.spawn(Opacity::OPAQUE).id();
+        let b = world.spawn(Opacity::OPAQUE).id();
+        let fade = world.spawn(CrossFadeSprite {
+            t: 0.25,
+            ..CrossFadeSprite::new(a, b)
+        }).id();
+
+        system.run((), &mut world);
+        system.apply_deferred(&mut world);
+        assert_eq!(world.get::<Opacity>(a).unwrap().opacity, 0.75);
+        assert_eq!(world.get::<Opacity>(b).unwrap().opacity, 0.25);
+        assert_eq!(world.get::<SpriteFit>(a), Some(&SpriteFit::Contain));
+        assert_eq!(world.get::<SpriteFit>(b), Some(&SpriteFit::Contain));
+
+        // Not rewritten unless `CrossFadeSprite` changes.
+        world.get_mut::<Opacity>(a).unwrap().opacity = 0.5;
+        system.run((), &mut world);
+        assert_eq!(world.get::<Opacity>(a).unwrap().opacity, 0.5);
+
+        world.get_mut::<CrossFadeSprite>(fade).unwrap().t = 2.0;
+        system.run((), &mut world);
+        assert_eq!(world.get::<Opacity>(a).unwrap().opacity, 0.0);
+        assert_eq!(world.get::<Opacity>(b).unwrap().opacity, 1.0);
+    }
+}